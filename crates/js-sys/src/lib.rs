@@ -2014,6 +2014,439 @@ impl<T: JsGeneric> Array<T> {
             array: self,
         }
     }
+
+    /// Returns `true` if the array has a `length` of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+
+    /// Counts the elements of the array for which `pred` returns `true`.
+    ///
+    /// Unlike `filter(...).length()`, this does not allocate an intermediate
+    /// JS array.
+    pub fn count_where(&self, pred: &mut dyn FnMut(T) -> bool) -> u32 {
+        self.iter()
+            .fold(0, |count, item| if pred(item) { count + 1 } else { count })
+    }
+
+    /// Returns the index of the first element for which `pred` returns
+    /// `true`, or `None` if there is no such element, mirroring
+    /// [`Iterator::position`].
+    ///
+    /// Unlike [`find_index`](Array::find_index), this does not use `-1` as a
+    /// "not found" sentinel.
+    pub fn position(&self, pred: &mut dyn FnMut(T) -> bool) -> Option<u32> {
+        self.iter().position(pred).map(|index| index as u32)
+    }
+
+    /// Returns the index of the last element for which `pred` returns
+    /// `true`, or `None` if there is no such element, mirroring
+    /// [`Iterator::rposition`].
+    ///
+    /// Elements are visited from the end of the array.
+    pub fn rposition(&self, pred: &mut dyn FnMut(T) -> bool) -> Option<u32> {
+        self.iter().rposition(pred).map(|index| index as u32)
+    }
+
+    /// Inserts `value` into an array that is sorted according to `cmp`,
+    /// keeping it sorted, and returns the index it was inserted at.
+    ///
+    /// The insertion point is found by binary search, after any elements
+    /// that compare equal to `value`, and the value is inserted with
+    /// `splice`. If the array is not sorted the position is unspecified.
+    pub fn binary_insert(&self, value: &T, cmp: &mut dyn FnMut(&T, &T) -> Ordering) -> u32 {
+        let (mut low, mut high) = (0, self.length());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if cmp(&self.get_unchecked(mid), value) == Ordering::Greater {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        self.splice(low, 0, value);
+        low
+    }
+
+    /// Calls `f` with the index and value of each element in order,
+    /// stopping at and returning the first `Err`.
+    ///
+    /// Unlike [`try_for_each`](Array::try_for_each), the iteration runs on
+    /// the Rust side rather than through `Array.prototype.forEach`, so the
+    /// error can be any Rust type and is returned as is.
+    pub fn try_for_each_rust<E>(
+        &self,
+        f: &mut dyn FnMut(u32, T) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.iter()
+            .zip(0..)
+            .try_for_each(|(item, index)| f(index, item))
+    }
+
+    /// Maps each element through `f` into a Rust `Vec`, in a single pass and
+    /// without creating an intermediate JS array.
+    pub fn to_vec_via<U>(&self, f: &mut dyn FnMut(T) -> U) -> Vec<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// Collects the elements into a Rust `Vec` of `f64`, failing with the
+    /// first element that is not a JS number.
+    ///
+    /// No coercion is applied, so numeric strings such as `"2"` are rejected
+    /// too. For an [`Array<Number>`], [`to_f64_vec`](Array::to_f64_vec) is
+    /// infallible.
+    pub fn to_number_vec(&self) -> Result<Vec<f64>, JsValue> {
+        self.iter()
+            .map(|item| {
+                let item: JsValue = item.into();
+                item.as_f64().ok_or(item)
+            })
+            .collect()
+    }
+
+    /// Converts each element with `U::try_from` into a Rust `Vec`, stopping
+    /// at and returning the first conversion error.
+    pub fn try_collect<U: TryFrom<JsValue>>(&self) -> Result<Vec<U>, U::Error> {
+        self.iter().map(|item| U::try_from(item.into())).collect()
+    }
+
+    /// Maps each element through `f` into a new array, stopping at and
+    /// returning the first `Err`.
+    ///
+    /// Unlike [`try_map`](Array::try_map), the iteration runs on the Rust
+    /// side, so the error can be any Rust type and is returned as is.
+    pub fn try_map_rust<U: JsGeneric, E>(
+        &self,
+        f: &mut dyn FnMut(T) -> Result<U, E>,
+    ) -> Result<Array<U>, E> {
+        let out = Array::<U>::new_typed();
+        for item in self.iter() {
+            out.push(&f(item)?);
+        }
+        Ok(out)
+    }
+
+    /// Returns a plain object mapping each index, as a string key (`"0"`,
+    /// `"1"`, ...), to the corresponding element.
+    ///
+    /// This is `Object.fromEntries(array.entries())`, which is handy for
+    /// JSON shapes that expect an object rather than an array.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries)
+    pub fn to_object(&self) -> Object<T> {
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = Object, js_name = fromEntries)]
+            fn from_index_entries<A>(entries: &Iterator<ArrayTuple<(Number, A)>>) -> Object<A>;
+        }
+
+        from_index_entries(&self.entries_typed())
+    }
+
+    /// Returns the indices `(i, j)` of the first pair of elements for which
+    /// `eq` returns `true`, or `None` if all elements are distinct.
+    ///
+    /// "First" means the smallest `j`, i.e. the earliest element that repeats
+    /// a previous one, with `i` the index of its first match. This performs a
+    /// quadratic scan that stops at the first match.
+    pub fn first_duplicate(&self, eq: &mut dyn FnMut(&T, &T) -> bool) -> Option<(u32, u32)> {
+        let items: Vec<T> = self.iter().collect();
+        for (j, b) in items.iter().enumerate() {
+            if let Some(i) = items[..j].iter().position(|a| eq(a, b)) {
+                return Some((i as u32, j as u32));
+            }
+        }
+        None
+    }
+
+    /// Divides the array into two new arrays at `mid`, mirroring
+    /// [`slice::split_at`].
+    ///
+    /// The first array contains the elements in `[0, mid)` and the second
+    /// the elements in `[mid, length)`. A `mid` past the end of the array is
+    /// clamped to its length. The original array is not modified.
+    pub fn split_at(&self, mid: u32) -> (Array<T>, Array<T>) {
+        let mid = mid.min(self.length());
+        #[cfg(js_sys_unstable_apis)]
+        let mid = mid as i32;
+        (self.slice(0, mid), self.slice_from(mid))
+    }
+
+    /// Returns the first element and a new array of the remaining elements,
+    /// or `None` if the array is empty, mirroring [`slice::split_first`].
+    pub fn split_first(&self) -> Option<(T, Array<T>)> {
+        let first = self.iter().next()?;
+        Some((first, self.slice_from(1)))
+    }
+
+    /// Returns the last element and a new array of the preceding elements,
+    /// or `None` if the array is empty, mirroring [`slice::split_last`].
+    pub fn split_last(&self) -> Option<(T, Array<T>)> {
+        let last = self.iter().next_back()?;
+        let len = self.length() - 1;
+        #[cfg(js_sys_unstable_apis)]
+        let len = len as i32;
+        Some((last, self.slice(0, len)))
+    }
+
+    /// Returns an iterator over `size`-element chunks of the array, starting
+    /// at the end, mirroring [`slice::rchunks`].
+    ///
+    /// Each chunk is a new array. If the length is not a multiple of `size`,
+    /// the last chunk yielded (the start of the array) is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn rchunks(&self, size: u32) -> impl core::iter::Iterator<Item = Array<T>> + '_ {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut end = self.length();
+        iter::from_fn(move || {
+            if end == 0 {
+                return None;
+            }
+            let start = end.saturating_sub(size);
+            #[cfg(not(js_sys_unstable_apis))]
+            let chunk = self.slice(start, end);
+            #[cfg(js_sys_unstable_apis)]
+            let chunk = self.slice(start as i32, end as i32);
+            end = start;
+            Some(chunk)
+        })
+    }
+
+    /// Returns a new array of the intermediate states produced by threading
+    /// an accumulator through the elements of the array.
+    ///
+    /// `f` is called with the mutable accumulator (starting at `init`) and
+    /// each element in turn, and the value it returns is pushed onto the
+    /// result. This mirrors [`Iterator::scan`](core::iter::Iterator::scan),
+    /// but collects every step, which makes it handy for prefix sums.
+    pub fn scan<A: JsGeneric>(&self, init: A, f: &mut dyn FnMut(&mut A, T) -> A) -> Array<A> {
+        let mut state = init;
+        let out = Array::<A>::new_typed();
+        for item in self.iter() {
+            out.push(&f(&mut state, item));
+        }
+        out
+    }
+
+    /// Returns a new array that alternates elements from `self` and `other`,
+    /// producing `[a0, b0, a1, b1, ...]`.
+    ///
+    /// Once the shorter array is exhausted, the remaining elements of the
+    /// longer one are appended in order. Neither input is modified.
+    pub fn interleave(&self, other: &Array<T>) -> Array<T> {
+        let out = Array::<T>::new_typed();
+        let (mut a, mut b) = (self.iter(), other.iter());
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => break,
+                (x, y) => {
+                    for item in x.into_iter().chain(y) {
+                        out.push(&item);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Returns whether the array contains `value`, like [`slice::contains`].
+    ///
+    /// This is [`Array::includes`] from index 0, so elements are compared
+    /// with `SameValueZero`: `NaN` matches `NaN`, and objects match only
+    /// themselves.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.includes(value, 0)
+    }
+
+    /// Returns a new array with `separator` inserted between each pair of
+    /// adjacent elements, producing `[a0, sep, a1, sep, a2]`, like the
+    /// unstable `Iterator::intersperse`.
+    ///
+    /// Empty and single-element arrays yield an unchanged copy.
+    pub fn intersperse(&self, separator: &T) -> Array<T> {
+        let out = Array::<T>::new_typed();
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                out.push(separator);
+            }
+            out.push(&item);
+        }
+        out
+    }
+
+    /// Returns a new array of length `n` made by repeating this array's
+    /// elements cyclically, like `iter().cycle().take(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this array is empty and `n` is non-zero.
+    pub fn cycle_take(&self, n: u32) -> Array<T> {
+        let len = self.length();
+        assert!(n == 0 || len > 0, "cannot cycle an empty array");
+        let out = Array::<T>::new_typed();
+        for index in 0..n {
+            out.push(&self.get_unchecked(index % len));
+        }
+        out
+    }
+
+    /// Run-length encodes the array, returning a `(value, count)` pair for
+    /// each run of consecutive elements that `same` considers equal.
+    ///
+    /// `same` is called with the first element of the current run and the
+    /// next element. The first element of each run is the one reported.
+    pub fn run_length_encode(
+        &self,
+        same: &mut dyn FnMut(&T, &T) -> bool,
+    ) -> Array<ArrayTuple<(T, Number)>> {
+        let out = Array::<ArrayTuple<(T, Number)>>::new_typed();
+        let push_run = |value: T, count: u32| {
+            out.push(&ArrayTuple::from((value, Number::from(count))));
+        };
+        let mut run: Option<(T, u32)> = None;
+        for item in self.iter() {
+            if let Some((value, count)) = &mut run {
+                if same(value, &item) {
+                    *count += 1;
+                    continue;
+                }
+            }
+            if let Some((value, count)) = run.replace((item, 1)) {
+                push_run(value, count);
+            }
+        }
+        if let Some((value, count)) = run {
+            push_run(value, count);
+        }
+        out
+    }
+
+    /// Groups the elements into a [`Map`] keyed by the result of `key_fn`.
+    ///
+    /// Keys are compared with the `SameValueZero` semantics of `Map`, and
+    /// appear in the order they are first produced. Within each bucket,
+    /// elements keep their original order. This is similar to
+    /// `Map.groupBy()`, but with a Rust closure and a typed result.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy)
+    pub fn chunk_by_key<K: JsGeneric>(&self, key_fn: &mut dyn FnMut(&T) -> K) -> Map<K, Array<T>> {
+        let out = Map::<K, Array<T>>::new_typed();
+        for item in self.iter() {
+            let key = key_fn(&item);
+            let bucket = out.get_checked(&key).unwrap_or_else(|| {
+                let bucket = Array::<T>::new_typed();
+                out.set(&key, &bucket);
+                bucket
+            });
+            bucket.push(&item);
+        }
+        out
+    }
+
+    /// Returns a new array containing the leading elements for which `pred`
+    /// returns `true`, stopping at the first element for which it returns
+    /// `false`, mirroring [`Iterator::take_while`].
+    pub fn take_while(&self, pred: &mut dyn FnMut(&T) -> bool) -> Array<T> {
+        let out = Array::<T>::new_typed();
+        for item in self.iter().take_while(pred) {
+            out.push(&item);
+        }
+        out
+    }
+
+    /// Returns a new array that skips the leading elements for which `pred`
+    /// returns `true` and contains every element from the first one for which
+    /// it returns `false`, mirroring [`Iterator::skip_while`].
+    pub fn skip_while(&self, pred: &mut dyn FnMut(&T) -> bool) -> Array<T> {
+        let out = Array::<T>::new_typed();
+        for item in self.iter().skip_while(pred) {
+            out.push(&item);
+        }
+        out
+    }
+
+    /// Resizes the array in place so that its `length` is `new_len`,
+    /// mirroring [`Vec::resize_with`].
+    ///
+    /// When growing, each new slot is filled with `f(index)` rather than
+    /// left as an empty slot as with [`set_length`](Array::set_length). When
+    /// shrinking, the array is truncated and `f` is not called.
+    pub fn resize_with(&self, new_len: u32, f: &mut dyn FnMut(u32) -> T) {
+        let len = self.length();
+        if new_len <= len {
+            self.set_length(new_len);
+            return;
+        }
+        for index in len..new_len {
+            self.set_ref(index, &f(index));
+        }
+    }
+}
+
+impl<T: JsGeneric + PartialEq> Array<T> {
+    /// Removes consecutive repeated elements from the array in place.
+    ///
+    /// Elements are compared with the same-value equality of
+    /// [`Object::is`], so `NaN` is deduplicated while `0` and `-0` are kept
+    /// distinct.
+    pub fn dedup(&self) {
+        let len = self.length();
+        if len < 2 {
+            return;
+        }
+        let mut prev = self.get_unchecked(0);
+        let mut write = 1;
+        for read in 1..len {
+            let item = self.get_unchecked(read);
+            if Object::is(prev.as_ref(), item.as_ref()) {
+                continue;
+            }
+            if write != read {
+                self.set_ref(write, &item);
+            }
+            write += 1;
+            prev = item;
+        }
+        self.set_length(write);
+    }
+}
+
+impl Array<Number> {
+    /// Returns the sum of the elements as an `f64`, or `0.0` for an empty
+    /// array.
+    pub fn sum_f64(&self) -> f64 {
+        self.iter().map(|n| n.value_of()).sum()
+    }
+
+    /// Returns the product of the elements as an `f64`, or `1.0` for an
+    /// empty array.
+    pub fn product_f64(&self) -> f64 {
+        self.iter().map(|n| n.value_of()).product()
+    }
+
+    /// Collects the elements into a Rust `Vec` of `f64`.
+    pub fn to_f64_vec(&self) -> Vec<f64> {
+        self.iter().map(|n| n.value_of()).collect()
+    }
+
+    /// Sorts the array in place in ascending numeric order and returns it.
+    ///
+    /// Plain [`Array::sort`] compares elements as strings, ordering `10`
+    /// before `2`. This sorts as with the comparator `(a, b) => a - b`
+    /// instead, except that `NaN`s are placed last rather than leaving the
+    /// order unspecified.
+    pub fn sort_numbers(&self) -> Array<Number> {
+        self.sort_by(&mut |a, b| {
+            let (a, b) = (a.value_of(), b.value_of());
+            a.partial_cmp(&b)
+                .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan())) as i32
+        })
+    }
 }
 
 impl<T: JsGeneric> core::iter::IntoIterator for Array<T> {
@@ -2074,6 +2507,16 @@ where
 }
 
 impl<T: JsGeneric> Array<T> {
+    /// Creates an array whose `length` is already `n`, ready to be filled in
+    /// place with [`Array::set_ref`].
+    ///
+    /// Unlike [`Vec::with_capacity`], the slots count towards the length from
+    /// the start: they are holes, which read back as `undefined` until they
+    /// are assigned, and which callbacks such as `forEach` skip over.
+    pub fn with_capacity(n: u32) -> Array<T> {
+        Array::new_with_length_typed(n)
+    }
+
     /// Collect an iterator into a typed `Array<T>`, projecting each item
     /// through its canonical [`JsGeneric`] via [`IntoJsGeneric`].
     ///
@@ -2345,6 +2788,27 @@ extern "C" {
 
 impl UpcastFrom<&[u8]> for ArrayBuffer {}
 
+impl ArrayBuffer {
+    /// Creates a view of element type `T` spanning the whole buffer, e.g.
+    /// `buffer.view::<Float32Array>()`.
+    ///
+    /// The buffer's `byte_length` must be a multiple of
+    /// `T::BYTES_PER_ELEMENT`; this is checked with a `debug_assert!`, and
+    /// the typed array constructor throws a `RangeError` otherwise.
+    pub fn view<T: TypedArrayView>(&self) -> T {
+        #[cfg(not(js_sys_unstable_apis))]
+        let byte_length = self.byte_length() as usize;
+        #[cfg(js_sys_unstable_apis)]
+        let byte_length = self.byte_length();
+        debug_assert!(
+            byte_length % T::BYTES_PER_ELEMENT == 0,
+            "buffer length {byte_length} is not a multiple of the element size {}",
+            T::BYTES_PER_ELEMENT,
+        );
+        T::view_of(self)
+    }
+}
+
 // SharedArrayBuffer
 #[wasm_bindgen]
 extern "C" {
@@ -2517,6 +2981,17 @@ extern "C" {
 // trait. The `TypedArray` name is kept for now to avoid a breaking change.
 pub trait TypedArray: JsGeneric {}
 
+/// A typed array type with a fixed element size, which can be created as a
+/// view over an [`ArrayBuffer`] with [`ArrayBuffer::view`].
+pub trait TypedArrayView: TypedArray {
+    /// The size in bytes of each element, as in the `BYTES_PER_ELEMENT`
+    /// property.
+    const BYTES_PER_ELEMENT: usize;
+
+    /// Creates a view over the whole of `buffer`.
+    fn view_of(buffer: &ArrayBuffer) -> Self;
+}
+
 impl TypedArray for DataView {}
 
 // Next major: use usize/isize for indices
@@ -2565,6 +3040,18 @@ pub mod Atomics {
             value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.add()` method adds a given value at a given
+        /// position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = add)]
+        pub fn add_u32(typed_array: &Uint32Array, index: u32, value: u32) -> Result<u32, JsValue>;
+
         /// The static `Atomics.and()` method computes a bitwise AND with a given
         /// value at a given position in the array, and returns the old value
         /// at that position.
@@ -2597,6 +3084,19 @@ pub mod Atomics {
             value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.and()` method computes a bitwise AND with a given
+        /// value at a given position in the array, and returns the old value
+        /// at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = and)]
+        pub fn and_u32(typed_array: &Uint32Array, index: u32, value: u32) -> Result<u32, JsValue>;
+
         /// The static `Atomics.compareExchange()` method exchanges a given
         /// replacement value at a given position in the array, if a given expected
         /// value equals the old value. It returns the old value at that position
@@ -2633,6 +3133,25 @@ pub mod Atomics {
             replacement_value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.compareExchange()` method exchanges a given
+        /// replacement value at a given position in the array, if a given expected
+        /// value equals the old value. It returns the old value at that position
+        /// whether it was equal to the expected value or not.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = compareExchange)]
+        pub fn compare_exchange_u32(
+            typed_array: &Uint32Array,
+            index: u32,
+            expected_value: u32,
+            replacement_value: u32,
+        ) -> Result<u32, JsValue>;
+
         /// The static `Atomics.exchange()` method stores a given value at a given
         /// position in the array and returns the old value at that position.
         /// This atomic operation guarantees that no other write happens
@@ -2663,6 +3182,22 @@ pub mod Atomics {
             value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.exchange()` method stores a given value at a given
+        /// position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = exchange)]
+        pub fn exchange_u32(
+            typed_array: &Uint32Array,
+            index: u32,
+            value: u32,
+        ) -> Result<u32, JsValue>;
+
         /// The static `Atomics.isLockFree()` method is used to determine
         /// whether to use locks or atomic operations. It returns true,
         /// if the given size is one of the `BYTES_PER_ELEMENT` property
@@ -2676,6 +3211,8 @@ pub mod Atomics {
         /// position in the array.
         ///
         /// You should use `load_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        /// For 8- and 16-bit arrays, `load_i8`, `load_u8`, `load_i16` and `load_u16`
+        /// return a value of the matching width.
         ///
         /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
         #[wasm_bindgen(js_namespace = Atomics, catch)]
@@ -2696,6 +3233,42 @@ pub mod Atomics {
             index: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.load()` method returns a value at a given
+        /// position in the array.
+        ///
+        /// This method is used to operate on an `Int8Array`, returning `i8`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = load)]
+        pub fn load_i8(typed_array: &Int8Array, index: u32) -> Result<i8, JsValue>;
+
+        /// The static `Atomics.load()` method returns a value at a given
+        /// position in the array.
+        ///
+        /// This method is used to operate on a `Uint8Array`, returning `u8`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = load)]
+        pub fn load_u8(typed_array: &Uint8Array, index: u32) -> Result<u8, JsValue>;
+
+        /// The static `Atomics.load()` method returns a value at a given
+        /// position in the array.
+        ///
+        /// This method is used to operate on an `Int16Array`, returning `i16`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = load)]
+        pub fn load_i16(typed_array: &Int16Array, index: u32) -> Result<i16, JsValue>;
+
+        /// The static `Atomics.load()` method returns a value at a given
+        /// position in the array.
+        ///
+        /// This method is used to operate on a `Uint16Array`, returning `u16`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = load)]
+        pub fn load_u16(typed_array: &Uint16Array, index: u32) -> Result<u16, JsValue>;
+
         /// The static `Atomics.notify()` method notifies up some agents that
         /// are sleeping in the wait queue.
         /// Note: This operation works with a shared `Int32Array` only.
@@ -2760,6 +3333,18 @@ pub mod Atomics {
             value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.or()` method computes a bitwise OR with a given value
+        /// at a given position in the array, and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = or)]
+        pub fn or_u32(typed_array: &Uint32Array, index: u32, value: u32) -> Result<u32, JsValue>;
+
         /// The static `Atomics.pause()` static method provides a micro-wait primitive that hints to the CPU
         /// that the caller is spinning while waiting on access to a shared resource. This allows the system
         /// to reduce the resources allocated to the core (such as power) or thread, without yielding the
@@ -2794,6 +3379,8 @@ pub mod Atomics {
         /// position in the array and returns that value.
         ///
         /// You should use `store_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        /// For 8- and 16-bit arrays, `store_i8`, `store_u8`, `store_i16` and `store_u16`
+        /// take and return a value of the matching width.
         ///
         /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
         #[wasm_bindgen(js_namespace = Atomics, catch)]
@@ -2816,6 +3403,43 @@ pub mod Atomics {
             value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.store()` method stores a given value at the given
+        /// position in the array and returns that value.
+        ///
+        /// This method is used to operate on an `Int8Array`, taking and returning `i8`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = store)]
+        pub fn store_i8(typed_array: &Int8Array, index: u32, value: i8) -> Result<i8, JsValue>;
+
+        /// The static `Atomics.store()` method stores a given value at the given
+        /// position in the array and returns that value.
+        ///
+        /// This method is used to operate on a `Uint8Array`, taking and returning `u8`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = store)]
+        pub fn store_u8(typed_array: &Uint8Array, index: u32, value: u8) -> Result<u8, JsValue>;
+
+        /// The static `Atomics.store()` method stores a given value at the given
+        /// position in the array and returns that value.
+        ///
+        /// This method is used to operate on an `Int16Array`, taking and returning `i16`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = store)]
+        pub fn store_i16(typed_array: &Int16Array, index: u32, value: i16) -> Result<i16, JsValue>;
+
+        /// The static `Atomics.store()` method stores a given value at the given
+        /// position in the array and returns that value.
+        ///
+        /// This method is used to operate on a `Uint16Array`, taking and returning `u16`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = store)]
+        pub fn store_u16(typed_array: &Uint16Array, index: u32, value: u16)
+            -> Result<u16, JsValue>;
+
         /// The static `Atomics.sub()` method subtracts a given value at a
         /// given position in the array and returns the old value at that position.
         /// This atomic operation guarantees that no other write happens
@@ -2846,6 +3470,18 @@ pub mod Atomics {
             value: i64,
         ) -> Result<i64, JsValue>;
 
+        /// The static `Atomics.sub()` method subtracts a given value at a
+        /// given position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = sub)]
+        pub fn sub_u32(typed_array: &Uint32Array, index: u32, value: u32) -> Result<u32, JsValue>;
+
         /// The static `Atomics.wait()` method verifies that a given
         /// position in an `Int32Array` still contains a given value
         /// and if so sleeps, awaiting a wakeup or a timeout.
@@ -2997,12 +3633,31 @@ pub mod Atomics {
             index: u32,
             value: i64,
         ) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.xor()` method computes a bitwise XOR
+        /// with a given value at a given position in the array,
+        /// and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `Uint32Array`, taking and returning `u32`
+        /// so that values above `i32::MAX` are not reinterpreted as negative.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = xor)]
+        pub fn xor_u32(typed_array: &Uint32Array, index: u32, value: u32) -> Result<u32, JsValue>;
     }
 }
 
 // BigInt
 #[wasm_bindgen]
 extern "C" {
+    /// A JS bigint primitive.
+    ///
+    /// To convert a `JsValue` with a runtime type check, use
+    /// [`JsCast::dyn_into`], which returns the original value as the error if
+    /// it is not a bigint. Note that `From<JsValue>`, and therefore the blanket
+    /// `TryFrom<JsValue>`, perform an unchecked cast.
     #[wasm_bindgen(extends = Object, is_type_of = |v| v.is_bigint(), typescript_type = "bigint")]
     #[derive(Clone, PartialEq, Eq)]
     pub type BigInt;
@@ -3099,6 +3754,59 @@ impl BigInt {
             .unchecked_into()
     }
 
+    /// Converts this `BigInt` to the nearest `f64`, as JS `Number(bigint)`
+    /// does.
+    ///
+    /// Precision is lost for values outside the safe integer range, see
+    /// [`BigInt::fits_in_safe_integer`]. Values too large for an `f64` become
+    /// infinite.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/Number#conversion_of_bigints)
+    pub fn to_f64_lossy(&self) -> f64 {
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_name = Number)]
+            fn bigint_to_number(value: &BigInt) -> f64;
+        }
+
+        bigint_to_number(self)
+    }
+
+    /// Returns whether this `BigInt` lies within
+    /// [`Number::MIN_SAFE_INTEGER`]`..=`[`Number::MAX_SAFE_INTEGER`], in
+    /// which case [`BigInt::to_f64_lossy`] converts it exactly.
+    pub fn fits_in_safe_integer(&self) -> bool {
+        let min = BigInt::from(Number::MIN_SAFE_INTEGER as i64);
+        let max = BigInt::from(Number::MAX_SAFE_INTEGER as i64);
+        &min <= self && self <= &max
+    }
+
+    /// Formats this value in decimal, inserting `separator` between every
+    /// `group` digits counting from the right, e.g. `-1234567` with a group
+    /// of 3 and `','` becomes `"-1,234,567"`.
+    ///
+    /// A `group` of 0 disables grouping.
+    pub fn to_grouped_string(&self, group: usize, separator: char) -> String {
+        let decimal = self.to_string_unchecked(10);
+        if group == 0 {
+            return decimal;
+        }
+        let (sign, digits) = match decimal.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", decimal.as_str()),
+        };
+
+        let mut out = String::with_capacity(decimal.len() + digits.len() / group);
+        out.push_str(sign);
+        for (i, digit) in digits.chars().enumerate() {
+            if i != 0 && (digits.len() - i) % group == 0 {
+                out.push(separator);
+            }
+            out.push(digit);
+        }
+        out
+    }
+
     /// Returns a tuple of this [`BigInt`]'s absolute value along with a
     /// [`bool`] indicating whether the [`BigInt`] was negative.
     fn abs(&self) -> (Self, bool) {
@@ -3204,6 +3912,59 @@ impl FromStr for BigInt {
     }
 }
 
+/// The error type returned when parsing a [`BigInt`] from a string with
+/// [`BigInt::try_from`] fails.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BigIntParseError {
+    /// The string was empty or contained only whitespace.
+    ///
+    /// JavaScript's `BigInt("")` evaluates to `0n`; this conversion rejects
+    /// it instead.
+    Empty,
+    /// The string contained a character that is not a valid digit; JavaScript
+    /// threw a `SyntaxError`.
+    InvalidDigit,
+    /// Any other exception thrown by the `BigInt` constructor.
+    Other(JsValue),
+}
+
+impl fmt::Display for BigIntParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BigIntParseError::Empty => fmt.write_str("cannot parse BigInt from empty string"),
+            BigIntParseError::InvalidDigit => fmt.write_str("invalid digit found in string"),
+            BigIntParseError::Other(err) => write!(fmt, "failed to parse BigInt: {err:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BigIntParseError {}
+
+impl TryFrom<&str> for BigInt {
+    type Error = BigIntParseError;
+
+    /// Parses a `BigInt` from a string, distinguishing the reason for
+    /// failure.
+    ///
+    /// A thrown `SyntaxError` is reported as
+    /// [`InvalidDigit`](BigIntParseError::InvalidDigit); engines word its
+    /// message differently, so the error's type is inspected rather than its
+    /// text.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s.trim().is_empty() {
+            return Err(BigIntParseError::Empty);
+        }
+        BigInt::new(&s.into()).map_err(|err| {
+            if err.is_instance_of::<SyntaxError>() {
+                BigIntParseError::InvalidDigit
+            } else {
+                BigIntParseError::Other(err.into())
+            }
+        })
+    }
+}
+
 impl fmt::Debug for BigInt {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3256,6 +4017,12 @@ impl fmt::UpperHex for BigInt {
 // Boolean
 #[wasm_bindgen]
 extern "C" {
+    /// A JS boolean primitive.
+    ///
+    /// To convert a `JsValue` with a runtime type check, use
+    /// [`JsCast::dyn_into`], which returns the original value as the error if
+    /// it is not a boolean. Note that `From<JsValue>`, and therefore the blanket
+    /// `TryFrom<JsValue>`, perform an unchecked cast.
     #[wasm_bindgen(extends = Object, is_type_of = |v| v.as_bool().is_some(), typescript_type = "boolean")]
     #[derive(Clone, PartialEq, Eq)]
     pub type Boolean;
@@ -3376,6 +4143,19 @@ extern "C" {
         byteLength: usize,
     ) -> DataView;
 
+    /// The `DataView` view provides a low-level interface for reading and
+    /// writing multiple number types in an `ArrayBuffer` irrespective of the
+    /// platform's endianness.
+    ///
+    /// The view is created without a length. Over a resizable `ArrayBuffer`
+    /// it is length-tracking: it always extends to the end of the buffer, so
+    /// [`byte_length`](DataView::byte_length) follows the buffer as it is
+    /// resized.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView)
+    #[wasm_bindgen(constructor)]
+    pub fn new_length_tracking(buffer: &ArrayBuffer, byteOffset: usize) -> DataView;
+
     /// The ArrayBuffer referenced by this view. Fixed at construction time and thus read only.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer)
@@ -3383,7 +4163,9 @@ extern "C" {
     pub fn buffer(this: &DataView) -> ArrayBuffer;
 
     /// The length (in bytes) of this view from the start of its ArrayBuffer.
-    /// Fixed at construction time and thus read only.
+    /// Fixed at construction time and thus read only, except for views made
+    /// with [`DataView::new_length_tracking`], whose length reflects the
+    /// current size of the buffer.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength)
     #[wasm_bindgen(method, getter, js_name = byteLength)]
@@ -3642,6 +4424,92 @@ extern "C" {
     pub fn set_float64_endian(this: &DataView, byte_offset: usize, value: f64, little_endian: bool);
 }
 
+/// A cursor over a [`DataView`] for reading binary data sequentially.
+///
+/// Each `read_*` method reads a value at the current position and advances
+/// the cursor past it. Reads that would go beyond the view's `byte_length`
+/// return a `RangeError` and leave the cursor unchanged.
+#[derive(Clone, Debug)]
+pub struct DataViewReader<'a> {
+    view: &'a DataView,
+    pos: usize,
+}
+
+impl<'a> DataViewReader<'a> {
+    /// Creates a reader positioned at the start of `view`.
+    pub fn new(view: &'a DataView) -> Self {
+        DataViewReader { view, pos: 0 }
+    }
+
+    /// Returns the current byte offset of the cursor within the view.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.view.byte_length().saturating_sub(self.pos)
+    }
+
+    /// Reserves `size` bytes at the cursor, returning the offset to read from.
+    fn advance(&mut self, size: usize) -> Result<usize, JsValue> {
+        if size > self.remaining() {
+            return Err(RangeError::new("DataViewReader: read out of bounds").into());
+        }
+        let offset = self.pos;
+        self.pos += size;
+        Ok(offset)
+    }
+
+    /// Reads an unsigned 8-bit integer.
+    pub fn read_u8(&mut self) -> Result<u8, JsValue> {
+        let offset = self.advance(1)?;
+        Ok(self.view.get_uint8(offset))
+    }
+
+    /// Reads a signed 8-bit integer.
+    pub fn read_i8(&mut self) -> Result<i8, JsValue> {
+        let offset = self.advance(1)?;
+        Ok(self.view.get_int8(offset))
+    }
+
+    /// Reads a little-endian unsigned 16-bit integer.
+    pub fn read_u16_le(&mut self) -> Result<u16, JsValue> {
+        let offset = self.advance(2)?;
+        Ok(self.view.get_uint16_endian(offset, true))
+    }
+
+    /// Reads a little-endian signed 16-bit integer.
+    pub fn read_i16_le(&mut self) -> Result<i16, JsValue> {
+        let offset = self.advance(2)?;
+        Ok(self.view.get_int16_endian(offset, true))
+    }
+
+    /// Reads a little-endian unsigned 32-bit integer.
+    pub fn read_u32_le(&mut self) -> Result<u32, JsValue> {
+        let offset = self.advance(4)?;
+        Ok(self.view.get_uint32_endian(offset, true))
+    }
+
+    /// Reads a little-endian signed 32-bit integer.
+    pub fn read_i32_le(&mut self) -> Result<i32, JsValue> {
+        let offset = self.advance(4)?;
+        Ok(self.view.get_int32_endian(offset, true))
+    }
+
+    /// Reads a little-endian 32-bit float.
+    pub fn read_f32_le(&mut self) -> Result<f32, JsValue> {
+        let offset = self.advance(4)?;
+        Ok(self.view.get_float32_endian(offset, true))
+    }
+
+    /// Reads a little-endian 64-bit float.
+    pub fn read_f64_le(&mut self) -> Result<f64, JsValue> {
+        let offset = self.advance(8)?;
+        Ok(self.view.get_float64_endian(offset, true))
+    }
+}
+
 // Error
 #[wasm_bindgen]
 extern "C" {
@@ -3728,6 +4596,24 @@ extern "C" {
     pub fn set_stack_trace_limit(value: &JsValue);
 }
 
+impl Error {
+    /// Creates a new `Error` whose message is the [`Display`](fmt::Display)
+    /// output of `e`.
+    ///
+    /// This is a convenient way to surface a Rust error to JavaScript.
+    pub fn from_display(e: &dyn fmt::Display) -> Error {
+        Error::new(&alloc::format!("{e}"))
+    }
+
+    /// Creates a new `Error` with the given message and `cause`.
+    ///
+    /// Shorthand for [`Error::new_with_error_options`] with an
+    /// [`ErrorOptions`] carrying `cause`.
+    pub fn with_cause(message: &str, cause: &JsValue) -> Error {
+        Error::new_with_error_options(message, &ErrorOptions::new(cause))
+    }
+}
+
 partialord_ord!(JsString);
 
 // EvalError
@@ -4553,6 +5439,9 @@ extern "C" {
         arg9: &JsValue,
     ) -> Function<fn() -> Ret>;
 
+    #[wasm_bindgen(method, js_name = bind, variadic)]
+    fn bind_spread(this: &Function, context: &JsValue, args: &[JsValue]) -> Function;
+
     /// The length property indicates the number of arguments expected by the function.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/length)
@@ -4803,6 +5692,32 @@ impl<T: JsFunction> Function<T> {
         args.apply_call(self, context)
     }
 
+    /// Calls the function with a given `this` value, spreading the elements
+    /// of `args` into individual arguments.
+    ///
+    /// This is equivalent to `fn.call(context, ...args)` in JavaScript, and
+    /// delegates to [`apply`](Function::apply).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Spread_syntax)
+    #[inline]
+    pub fn call_spread(&self, context: &JsValue, args: &Array) -> Result<T::Ret, JsValue> {
+        self.apply(context, args)
+    }
+
+    /// Returns a new function with `this` bound to `undefined` and `args`
+    /// prepended to the arguments of every call.
+    ///
+    /// This is equivalent to `fn.bind(undefined, ...args)` in JavaScript, and
+    /// is useful when the number of leading arguments is only known at
+    /// runtime. For a fixed number of arguments, prefer
+    /// [`bindn`](Function::bindn), which preserves the function's type.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    pub fn partial(&self, args: &[JsValue]) -> Function {
+        self.unchecked_ref::<Function>()
+            .bind_spread(&JsValue::undefined(), args)
+    }
+
     /// The `bind()` method creates a new function that, when called, has its
     /// `this` keyword set to the provided value, with a given sequence of
     /// arguments preceding any provided when the new function is called.
@@ -4924,6 +5839,23 @@ impl<F: JsFunction> Function<F> {
     {
         closure.into_js_value().unchecked_into()
     }
+
+    /// Checks that this function declares exactly `n` parameters, as reported
+    /// by its `length` property, returning a `TypeError` otherwise.
+    ///
+    /// Useful for validating host-provided callbacks up front. Note that
+    /// `length` does not count rest parameters or parameters following the
+    /// first one with a default value.
+    pub fn expect_arity(&self, n: u32) -> Result<(), JsValue> {
+        let length = self.length();
+        if length != n {
+            return Err(TypeError::new(&alloc::format!(
+                "expected a function of arity {n}, found arity {length}"
+            ))
+            .into());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(not(js_sys_unstable_apis))]
@@ -5239,6 +6171,37 @@ extern "C" {
         callback: &mut dyn FnMut(V, K) -> Result<(), JsError>,
     ) -> Result<(), JsValue>;
 
+    /// The `Map.groupBy()` static method groups the elements of `items`
+    /// according to the key returned by `callback` for each element, which
+    /// is called with the element and its index.
+    ///
+    /// Keys are compared with `SameValueZero` and appear in the order they
+    /// are first returned. Within each bucket, elements keep their iteration
+    /// order.
+    ///
+    /// `Map.groupBy()` is ES2024 and is missing on older engines such as
+    /// Node.js 20.
+    ///
+    /// **Note:** Consider using [`Map::try_group_by`] if the callback might throw an error.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy)
+    #[wasm_bindgen(static_method_of = Map, js_name = groupBy)]
+    pub fn group_by<K, V, I: Iterable<Item = V>>(
+        items: &I,
+        callback: &mut dyn FnMut(V, u32) -> K,
+    ) -> Map<K, Array<V>>;
+
+    /// The `Map.groupBy()` static method groups the elements of `items`
+    /// according to the key returned by `callback` for each element.
+    /// _(Fallible variation)_
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy)
+    #[wasm_bindgen(static_method_of = Map, js_name = groupBy, catch)]
+    pub fn try_group_by<K, V, I: Iterable<Item = V>>(
+        items: &I,
+        callback: &mut dyn FnMut(V, u32) -> Result<K, JsError>,
+    ) -> Result<Map<K, Array<V>>, JsValue>;
+
     /// The `get()` method returns a specified element from a Map object.
     /// Returns `undefined` if the key is not found.
     ///
@@ -5264,6 +6227,9 @@ extern "C" {
     #[wasm_bindgen(method, js_name = get)]
     pub fn get_checked<K, V>(this: &Map<K, V>, key: &K) -> Option<V>;
 
+    #[wasm_bindgen(method, js_name = get)]
+    fn get_raw<K, V>(this: &Map<K, V>, key: &K) -> JsValue;
+
     /// The `has()` method returns a boolean indicating whether an element with
     /// the specified key exists or not.
     ///
@@ -5346,6 +6312,99 @@ impl<K, V> Iterable for Map<K, V> {
     type Item = ArrayTuple<(K, V)>;
 }
 
+// Unlike `Array`, `Map` had no erased `FromIterator` impl to stay compatible
+// with, so these collect `(K, V)` pairs into a typed `Map<K, V>` directly.
+
+impl<K: JsGeneric, V: JsGeneric> core::iter::FromIterator<(K, V)> for Map<K, V> {
+    fn from_iter<I>(iter: I) -> Map<K, V>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut out = Map::new_typed();
+        out.extend(iter);
+        out
+    }
+}
+
+impl<K: JsGeneric, V: JsGeneric> core::iter::Extend<(K, V)> for Map<K, V> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in iter {
+            self.set(&key, &value);
+        }
+    }
+}
+
+impl<K: JsGeneric, V: FromWasmAbi + JsGeneric> Map<K, V> {
+    /// Returns an iterator over the `(key, value)` pairs of the map, in
+    /// insertion order.
+    ///
+    /// Iteration silently stops at the first error raised by the underlying
+    /// JS iterator. Use [`Map::try_iter`] to observe such errors.
+    pub fn iter(&self) -> impl core::iter::Iterator<Item = (K, V)> {
+        self.try_iter().map_while(Result::ok)
+    }
+
+    /// Returns an iterator over the `(key, value)` pairs of the map, in
+    /// insertion order, surfacing any error raised by the underlying JS
+    /// iterator.
+    pub fn try_iter(&self) -> impl core::iter::Iterator<Item = Result<(K, V), JsValue>> {
+        self.entries_typed()
+            .into_iter()
+            .map(|entry| entry.map(|t| t.into_tuple()))
+    }
+
+    /// Returns the value for `key`, first inserting the value returned by
+    /// `default` if the map has no such key, like
+    /// `HashMap::entry(key).or_insert_with(default)`.
+    ///
+    /// `default` is only called when `key` is absent, as checked by
+    /// [`Map::has`]. An existing `undefined` value is returned as is.
+    pub fn get_or_insert_with(&self, key: &K, default: &mut dyn FnMut() -> V) -> V {
+        if self.has(key) {
+            return self.get_raw(key).unchecked_into();
+        }
+        let value = default();
+        self.set(key, &value);
+        value
+    }
+
+    /// Serializes the map as a JSON object string.
+    ///
+    /// The map is first converted to a plain object with `Object.fromEntries()`,
+    /// so keys are coerced to property names and later duplicates win. Errors
+    /// thrown by `JSON.stringify`, e.g. for `BigInt` values or cycles, are
+    /// propagated. See [`Map::from_json_str`] for the reverse direction.
+    pub fn to_json_string(&self) -> Result<String, JsValue> {
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(catch, js_namespace = Object, js_name = fromEntries)]
+            fn object_from_map(map: &JsValue) -> Result<Object, JsValue>;
+        }
+
+        let object = object_from_map(self)?;
+        Ok(JSON::stringify(&object)?.into())
+    }
+}
+
+impl Map<JsString, JsValue> {
+    /// Parses `text` as a JSON object and collects its properties into a map,
+    /// in the order returned by `Object.entries()`.
+    ///
+    /// Returns a `SyntaxError` if `text` is not valid JSON and a `TypeError`
+    /// if it does not describe an object. JSON arrays are rejected too,
+    /// rather than being read as a map from indices to elements.
+    pub fn from_json_str(text: &str) -> Result<Map<JsString, JsValue>, JsValue> {
+        let value = JSON::parse(text)?;
+        if !value.is_object() || Array::is_array(&value) {
+            return Err(TypeError::new("JSON value is not an object").into());
+        }
+        Object::to_map(value.unchecked_ref())
+    }
+}
+
 // Iterator
 #[wasm_bindgen]
 extern "C" {
@@ -5363,6 +6422,87 @@ extern "C" {
     /// non-object value") will be thrown.
     #[wasm_bindgen(catch, method)]
     pub fn next<T: FromWasmAbi>(this: &Iterator<T>) -> Result<IteratorNext<T>, JsValue>;
+
+    // Iterator helpers
+    //
+    // `map` and `filter` are lazy: the callback is invoked as the returned
+    // iterator is consumed, so the closure must outlive that iterator.
+
+    /// The `map()` method returns a new iterator that yields each value of
+    /// this iterator transformed by `callback`.
+    ///
+    /// This and the other iterator helpers (ES2025) live on
+    /// `Iterator.prototype`, which built-in iterators such as array, map, set
+    /// and string iterators and generators inherit from. Iterators written as
+    /// plain objects with a `next()` method do not, and older engines such as
+    /// Node.js 20 lack the helpers entirely. Check that
+    /// `Reflect::has_str(&global(), &"Iterator".into())` is `true` before
+    /// relying on them.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/map)
+    #[wasm_bindgen(method)]
+    pub fn map<'a, T, U>(
+        this: &Iterator<T>,
+        callback: &ScopedClosure<'a, dyn FnMut(T, u32) -> U>,
+    ) -> Iterator<U>;
+
+    /// The `filter()` method returns a new iterator that yields only the
+    /// values of this iterator for which `predicate` returns `true`.
+    ///
+    /// This is an ES2025 iterator helper and may be missing; see
+    /// [`Iterator::map`] for how to detect support.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/filter)
+    #[wasm_bindgen(method)]
+    pub fn filter<'a, T>(
+        this: &Iterator<T>,
+        predicate: &ScopedClosure<'a, dyn FnMut(T, u32) -> bool>,
+    ) -> Iterator<T>;
+
+    /// The `take()` method returns a new iterator that yields at most `limit`
+    /// values of this iterator and then finishes.
+    ///
+    /// Not available on engines without ES2025 iterator helpers; see
+    /// [`Iterator::map`].
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/take)
+    #[wasm_bindgen(method)]
+    pub fn take<T>(this: &Iterator<T>, limit: u32) -> Iterator<T>;
+
+    /// The `drop()` method returns a new iterator that skips the first `limit`
+    /// values of this iterator and yields the rest.
+    ///
+    /// Not available on engines without ES2025 iterator helpers; see
+    /// [`Iterator::map`].
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/drop)
+    #[wasm_bindgen(method)]
+    pub fn drop<T>(this: &Iterator<T>, limit: u32) -> Iterator<T>;
+
+    /// The `reduce()` method runs `callback` on each value of this iterator,
+    /// passing in the return value from the calculation on the preceding
+    /// value, and returns the final result.
+    ///
+    /// This is an ES2025 iterator helper and may be missing; see
+    /// [`Iterator::map`] for how to detect support.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/reduce)
+    #[wasm_bindgen(method)]
+    pub fn reduce<T, A>(
+        this: &Iterator<T>,
+        callback: &mut dyn FnMut(A, T, u32) -> A,
+        initial_value: &A,
+    ) -> A;
+
+    /// The `toArray()` method consumes this iterator and returns a new array
+    /// with all of the values it yielded.
+    ///
+    /// Not available on engines without ES2025 iterator helpers; see
+    /// [`Iterator::map`].
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/toArray)
+    #[wasm_bindgen(method, js_name = toArray)]
+    pub fn to_array<T>(this: &Iterator<T>) -> Array<T>;
 }
 
 impl<T> UpcastFrom<Iterator<T>> for Object {}
@@ -5545,6 +6685,17 @@ pub fn try_iter(val: &JsValue) -> Result<Option<IntoIter<JsValue>>, JsValue> {
     Ok(Some(it.into_iter()))
 }
 
+/// Collect the values of any iterable `value` into a `Vec<T>` using the JS
+/// iteration protocol and `Symbol.iterator`.
+///
+/// This is the typed counterpart to [`try_iter`]: each yielded value is cast
+/// to `T` without checking. A `TypeError` is returned if `value` is not
+/// iterable, and any exception thrown while iterating is propagated.
+pub fn collect_iterable<T: FromWasmAbi + JsGeneric>(value: &JsValue) -> Result<Vec<T>, JsValue> {
+    let iter = try_iter(value)?.ok_or_else(|| TypeError::new("value is not iterable"))?;
+    iter.map(|item| item.map(JsCast::unchecked_into)).collect()
+}
+
 /// Trait for JavaScript types that implement the iterable protocol via `Symbol.iterator`.
 ///
 /// Types implementing this trait can be iterated over using JavaScript's iteration
@@ -5927,11 +7078,118 @@ pub mod Math {
         #[wasm_bindgen(thread_local_v2, js_namespace = Math)]
         pub static PI: f64;
     }
+
+    /// Linearly interpolates between `a` and `b`, returning `a + (b - a) * t`.
+    ///
+    /// `t` is not clamped, so values outside `[0, 1]` extrapolate beyond the
+    /// endpoints.
+    #[inline]
+    pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+        a + (b - a) * t
+    }
+
+    /// The inverse of [`lerp`]: returns the `t` for which `lerp(a, b, t)`
+    /// equals `v`, i.e. `(v - a) / (b - a)`.
+    ///
+    /// The result is not finite when `a == b`.
+    #[inline]
+    pub fn inverse_lerp(a: f64, b: f64, v: f64) -> f64 {
+        (v - a) / (b - a)
+    }
+
+    /// Returns `n!`, the product of the integers from `1` to `n`.
+    ///
+    /// The result is exact while it does not exceed
+    /// [`Number::MAX_SAFE_INTEGER`](crate::Number::MAX_SAFE_INTEGER), which
+    /// holds up to `n = 18`. Larger factorials are rounded to the nearest
+    /// `f64`, and are infinite from `n = 171` on.
+    pub fn factorial(n: u32) -> f64 {
+        // Stop early once the product overflows, rather than looping up to
+        // `u32::MAX`.
+        let mut product = 1.0;
+        for i in 2..=n {
+            product *= f64::from(i);
+            if product.is_infinite() {
+                break;
+            }
+        }
+        product
+    }
+
+    /// Returns the natural logarithm of the absolute value of the gamma
+    /// function at `x`.
+    ///
+    /// JS has no gamma function, so this uses the Lanczos approximation
+    /// (`g = 7`, `n = 9`), which is accurate to about 15 significant digits.
+    /// Values below `0.5` use the reflection formula. The result is infinite
+    /// at the poles `0, -1, -2, ...`.
+    pub fn ln_gamma(x: f64) -> f64 {
+        const G: f64 = 7.0;
+        const COEFFICIENTS: [f64; 9] = [
+            0.9999999999998099,
+            676.5203681218851,
+            -1259.1392167224028,
+            771.3234287776531,
+            -176.6150291621406,
+            12.507343278686905,
+            -0.13857109526572012,
+            9.984369578019572e-6,
+            1.5056327351493116e-7,
+        ];
+        // ln(2π) / 2
+        const HALF_LN_TWO_PI: f64 = 0.9189385332046727;
+
+        if x <= 0.0 && x == floor(x) {
+            return f64::INFINITY;
+        }
+        if x < 0.5 {
+            let pi = core::f64::consts::PI;
+            return log(pi / abs(sin(pi * x))) - ln_gamma(1.0 - x);
+        }
+
+        let x = x - 1.0;
+        let t = x + G + 0.5;
+        let sum = COEFFICIENTS[1..]
+            .iter()
+            .zip(1..)
+            .fold(COEFFICIENTS[0], |sum, (c, i)| sum + c / (x + f64::from(i)));
+        HALF_LN_TWO_PI + (x + 0.5) * log(t) - t + log(sum)
+    }
+
+    /// Rounds `x` to the nearest integer, rounding ties to the nearest even
+    /// integer ("banker's rounding"), like [`f64::round_ties_even`].
+    ///
+    /// [`round`] instead rounds ties towards `+Infinity`, e.g. `round(2.5)`
+    /// is `3` while `round_ties_even(2.5)` is `2`. The sign of zero is
+    /// preserved, and `NaN` and infinities are returned unchanged.
+    pub fn round_ties_even(x: f64) -> f64 {
+        if !x.is_finite() {
+            return x;
+        }
+        let down = floor(x);
+        let fraction = x - down;
+        let rounded = if fraction > 0.5 || (fraction == 0.5 && down % 2.0 != 0.0) {
+            down + 1.0
+        } else {
+            down
+        };
+        if rounded == 0.0 && x.is_sign_negative() {
+            -0.0
+        } else {
+            rounded
+        }
+    }
 }
 
 // Number.
 #[wasm_bindgen]
 extern "C" {
+    /// A JS number primitive.
+    ///
+    /// To convert a `JsValue` with a runtime type check, use
+    /// [`JsCast::dyn_into`], which returns the original value as the error if
+    /// it is not a number. Note that `From<JsValue>`, and therefore the blanket
+    /// `TryFrom<JsValue>`, perform an unchecked cast.
     #[wasm_bindgen(extends = Object, is_type_of = |v| v.as_f64().is_some(), typescript_type = "number")]
     #[derive(Clone, PartialEq)]
     pub type Number;
@@ -5998,6 +7256,9 @@ extern "C" {
     #[wasm_bindgen(method, js_name = toLocaleString)]
     pub fn to_locale_string(this: &Number, locale: &str) -> JsString;
 
+    #[wasm_bindgen(method, catch, js_name = toLocaleString)]
+    fn try_to_locale_string(this: &Number, locale: &str) -> Result<JsString, JsValue>;
+
     /// The `toLocaleString()` method returns a string with a language sensitive
     /// representation of this number.
     ///
@@ -6109,6 +7370,72 @@ impl Number {
     pub fn unsigned_shr(&self, rhs: &Self) -> Self {
         Number::from(JsValue::as_ref(self).unsigned_shr(JsValue::as_ref(rhs)))
     }
+
+    /// Returns the sign of this number as computed by `Math.sign()`: `1` for
+    /// positive numbers, `-1` for negative numbers, and the number itself for
+    /// `+0`, `-0` and `NaN`.
+    ///
+    /// Unlike [`f64::signum`], zeros are not mapped to `±1`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sign)
+    #[inline]
+    pub fn signum(&self) -> Number {
+        Number::from(Math::sign(self.value_of()))
+    }
+
+    /// Returns whether this number lies within the inclusive range
+    /// `[min, max]`.
+    ///
+    /// Returns `false` if this number, `min` or `max` is `NaN`.
+    #[inline]
+    pub fn is_between(&self, min: &Number, max: &Number) -> bool {
+        min <= self && self <= max
+    }
+
+    /// Formats this number for `locale` with grouping separators, e.g.
+    /// `"1,234,567"` for `"en-US"`.
+    ///
+    /// This is `toLocaleString(locale)`, which groups digits by default, but
+    /// returns a `RangeError` rather than throwing if `locale` is not a valid
+    /// BCP 47 language tag.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString)
+    pub fn to_grouped_string(&self, locale: &str) -> Result<JsString, JsValue> {
+        self.try_to_locale_string(locale)
+    }
+
+    /// Returns the number of leading zero bits in the 32-bit unsigned integer
+    /// interpretation of this number, as computed by `Math.clz32()`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/clz32)
+    #[inline]
+    pub fn leading_zeros_u32(&self) -> u32 {
+        Math::clz32(self.to_uint32() as i32)
+    }
+
+    /// Returns whether the 32-bit unsigned integer interpretation of this
+    /// number is a power of two.
+    #[inline]
+    pub fn is_power_of_two_u32(&self) -> bool {
+        self.to_uint32().is_power_of_two()
+    }
+
+    /// Returns the smallest power of two greater than or equal to the 32-bit
+    /// unsigned integer interpretation of this number.
+    ///
+    /// The result is returned as an `f64` since it may be `2^32`.
+    #[inline]
+    pub fn next_power_of_two_u32(&self) -> f64 {
+        match self.to_uint32() {
+            0 | 1 => 1.0,
+            n => (1u64 << (32 - Math::clz32((n - 1) as i32))) as f64,
+        }
+    }
+
+    // The JS `ToUint32` conversion, i.e. `x >>> 0`.
+    fn to_uint32(&self) -> u32 {
+        self.unsigned_shr(&Number::from(0)).value_of() as u32
+    }
 }
 
 macro_rules! number_from {
@@ -6278,6 +7605,91 @@ impl FromStr for Number {
     }
 }
 
+/// A [`Number`] with total equality, ordering and hashing, so that JS numbers
+/// can key Rust collections such as `HashMap` and `BTreeMap`.
+///
+/// Values are compared by their `f64` bit pattern after mapping every `NaN`
+/// to a single canonical `NaN`. Equality therefore matches `Object.is()`:
+/// all `NaN`s are equal to each other, while `0` and `-0` are distinct. The
+/// ordering is that of [`f64::total_cmp`], placing `NaN` after `Infinity`.
+#[derive(Clone, Debug)]
+pub struct OrderedNumber(Number);
+
+impl OrderedNumber {
+    /// Wraps `number`.
+    #[inline]
+    pub fn new(number: Number) -> OrderedNumber {
+        OrderedNumber(number)
+    }
+
+    /// Returns the wrapped [`Number`].
+    #[inline]
+    pub fn into_inner(self) -> Number {
+        self.0
+    }
+
+    /// Returns the value as an `f64` with `NaN` canonicalized.
+    fn canonical(&self) -> f64 {
+        let value = self.0.value_of();
+        if value.is_nan() {
+            f64::NAN
+        } else {
+            value
+        }
+    }
+}
+
+impl From<Number> for OrderedNumber {
+    #[inline]
+    fn from(number: Number) -> OrderedNumber {
+        OrderedNumber(number)
+    }
+}
+
+impl From<OrderedNumber> for Number {
+    #[inline]
+    fn from(number: OrderedNumber) -> Number {
+        number.0
+    }
+}
+
+impl AsRef<Number> for OrderedNumber {
+    #[inline]
+    fn as_ref(&self) -> &Number {
+        &self.0
+    }
+}
+
+impl PartialEq for OrderedNumber {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical().to_bits() == other.canonical().to_bits()
+    }
+}
+
+impl Eq for OrderedNumber {}
+
+impl PartialOrd for OrderedNumber {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedNumber {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical().total_cmp(&other.canonical())
+    }
+}
+
+impl core::hash::Hash for OrderedNumber {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.canonical().to_bits().hash(state);
+    }
+}
+
 // Date.
 #[wasm_bindgen]
 extern "C" {
@@ -6779,6 +8191,72 @@ extern "C" {
     pub fn to_temporal_instant(this: &Date) -> Temporal::Instant;
 }
 
+impl Date {
+    /// Creates a `Date` like [`Date::new`], returning `None` if the result
+    /// is an "Invalid Date", i.e. its time value is `NaN`.
+    ///
+    /// This makes parsing untrusted date strings fail cleanly.
+    pub fn try_new(init: &JsValue) -> Option<Date> {
+        let date = Date::new(init);
+        if date.get_time().is_nan() {
+            None
+        } else {
+            Some(date)
+        }
+    }
+
+    /// Returns the day of the year in local time, from `1` for January 1st
+    /// to `365`, or `366` in leap years, for December 31st.
+    pub fn day_of_year(&self) -> u32 {
+        const DAYS_BEFORE_MONTH: [u32; 12] =
+            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+        let month = self.get_month();
+        let leap_day = u32::from(month > 1 && is_leap_year(self.get_full_year() as i32));
+        DAYS_BEFORE_MONTH[month as usize] + leap_day + self.get_date()
+    }
+
+    /// Returns the ISO 8601 week-numbering year and week number of this date
+    /// in local time.
+    ///
+    /// ISO weeks start on Monday, and week 1 is the week containing the
+    /// year's first Thursday. Hence the dates around January 1st may belong
+    /// to the last week of the previous year, and those around December 31st
+    /// to week 1 of the next year, which is why the year is signed: January
+    /// 1st of year 0 belongs to the last week of year -1.
+    pub fn iso_week(&self) -> (i32, u32) {
+        // Monday = 1, ..., Sunday = 7.
+        let weekday = (self.get_day() + 6) % 7 + 1;
+        let year = self.get_full_year() as i32;
+        let week = (self.day_of_year() + 10 - weekday) / 7;
+        if week == 0 {
+            (year - 1, iso_weeks_in_year(year - 1))
+        } else if week > iso_weeks_in_year(year) {
+            (year + 1, 1)
+        } else {
+            (year, week)
+        }
+    }
+}
+
+// Takes the year signed, as `get_full_year()` wraps negative years.
+fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+// A year has 53 ISO weeks if it starts on a Thursday, or on a Wednesday in a
+// leap year, i.e. if it ends on a Thursday or the previous one ends on a
+// Wednesday.
+fn iso_weeks_in_year(year: i32) -> u32 {
+    let dec_31_weekday =
+        |y: i32| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)).rem_euclid(7);
+    if dec_31_weekday(year) == 4 || dec_31_weekday(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
 // Property Descriptor.
 #[wasm_bindgen]
 extern "C" {
@@ -7045,6 +8523,24 @@ extern "C" {
         object: &Object<T>,
     ) -> Result<Array<ArrayTuple<(JsString, T)>>, JsValue>;
 
+    /// The `Object.entries()` method returns an array of a given
+    /// object's own enumerable string-keyed property [key, value] pairs.
+    ///
+    /// The pairs follow the `OrdinaryOwnPropertyKeys` ordering of the
+    /// specification, which is not plain insertion order:
+    ///
+    /// 1. Integer-like keys (array indices such as `"0"` or `"42"`) come
+    ///    first, in ascending numeric order.
+    /// 2. All other string keys follow, in property creation order.
+    ///
+    /// For example, an object created with keys `"2"`, `"1"`, `"b"` and `"a"`
+    /// (in that order) yields the entries for `"1"`, `"2"`, `"b"`, `"a"`.
+    /// Symbol-keyed properties are never included.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries)
+    #[wasm_bindgen(static_method_of = Object, js_name = entries)]
+    pub fn ordered_entries(object: &Object) -> Array<ArrayTuple<(JsString, JsValue)>>;
+
     /// The `Object.freeze()` method freezes an object: that is, prevents new
     /// properties from being added to it; prevents existing properties from
     /// being removed; and prevents existing properties, or their enumerability,
@@ -7133,6 +8629,23 @@ extern "C" {
         prop: &Symbol,
     ) -> Result<PropertyDescriptor<JsValue>, JsValue>;
 
+    /// The `Object.getOwnPropertyDescriptor()` method returns a
+    /// property descriptor for an own property (that is, one directly
+    /// present on an object and not in the object's prototype chain)
+    /// of a given object.
+    ///
+    /// Returns `None` if `obj` has no own property `prop`. The key may be a
+    /// string or a symbol. Use the getters of [`PropertyDescriptor`] to tell
+    /// data properties (`value`, `writable`) from accessor properties (`get`,
+    /// `set`).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptor)
+    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyDescriptor)]
+    pub fn get_own_property_descriptor_checked<T>(
+        obj: &Object<T>,
+        prop: &JsValue,
+    ) -> Option<PropertyDescriptor<T>>;
+
     /// The `Object.getOwnPropertyDescriptors()` method returns all own
     /// property descriptors of a given object.
     ///
@@ -7193,6 +8706,37 @@ extern "C" {
     #[wasm_bindgen(static_method_of = Object, js_name = getPrototypeOf)]
     pub fn get_prototype_of(obj: &JsValue) -> Object;
 
+    /// The `Object.groupBy()` static method groups the elements of `items`
+    /// into the properties of a null-prototype object, according to the
+    /// property key returned by `callback` for each element, which is called
+    /// with the element and its index.
+    ///
+    /// Within each property, elements keep their iteration order.
+    ///
+    /// This is an ES2024 addition, so older engines such as Node.js 20 do
+    /// not provide it.
+    ///
+    /// **Note:** Consider using [`Object::try_group_by`] if the callback might throw an error.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy)
+    #[wasm_bindgen(static_method_of = Object, js_name = groupBy)]
+    pub fn group_by<T, I: Iterable<Item = T>>(
+        items: &I,
+        callback: &mut dyn FnMut(T, u32) -> JsValue,
+    ) -> Object<Array<T>>;
+
+    /// The `Object.groupBy()` static method groups the elements of `items`
+    /// into the properties of a null-prototype object, according to the
+    /// property key returned by `callback` for each element.
+    /// _(Fallible variation)_
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy)
+    #[wasm_bindgen(static_method_of = Object, js_name = groupBy, catch)]
+    pub fn try_group_by<T, I: Iterable<Item = T>>(
+        items: &I,
+        callback: &mut dyn FnMut(T, u32) -> Result<JsValue, JsError>,
+    ) -> Result<Object<Array<T>>, JsValue>;
+
     /// The `hasOwnProperty()` method returns a boolean indicating whether the
     /// object has the specified property as its own property (as opposed to
     /// inheriting it).
@@ -7421,6 +8965,196 @@ impl Object {
             None
         }
     }
+
+    /// Deep-merges the enumerable own properties of `overlay` into a copy of
+    /// `base`, returning the merged object. Neither input is modified.
+    ///
+    /// Properties of `overlay` win on conflicts. When both sides hold a plain
+    /// object (one whose prototype is `Object.prototype` or `null`) under the
+    /// same key, the two are merged recursively; any other value, including
+    /// arrays and class instances, replaces the base value as a whole.
+    /// Nested plain objects in the result are always fresh copies, so
+    /// mutating them leaves both inputs untouched; other values are shared.
+    ///
+    /// Returns a `RangeError` if the objects are nested more than 64 levels
+    /// deep (e.g. because of a cycle), and propagates any exception thrown by
+    /// a property getter.
+    pub fn merge_deep(base: &Object, overlay: &Object) -> Result<Object, JsValue> {
+        merge_deep_at(base, overlay, 0)
+    }
+
+    /// Returns a shallow copy of `obj` that shares its prototype.
+    ///
+    /// Unlike `Object::assign(&Object::new(), obj)`, the copy is created with
+    /// `Object.create(Object.getPrototypeOf(obj))`, so inherited methods
+    /// remain reachable. Own enumerable properties, including symbol-keyed
+    /// ones, are then copied as by `Object.assign`.
+    pub fn shallow_clone(obj: &Object) -> Object {
+        let clone = Object::create(&Object::get_prototype_of(obj));
+        Object::assign(&clone, obj)
+    }
+
+    /// Copies the own enumerable properties of `source` onto `target`, as by
+    /// `Object.assign`, and returns `target` itself.
+    ///
+    /// Unlike [`Object::assign`], which returns an untyped [`Object`], this
+    /// hands back the original typed handle, so a typed wrapper can be
+    /// augmented in place without casting. Exceptions thrown while assigning,
+    /// e.g. to a frozen `target`, are rethrown.
+    pub fn spread_into<T: AsRef<Object>>(target: T, source: &Object) -> T {
+        Object::assign(target.as_ref(), source);
+        target
+    }
+
+    /// Builds a [`Map`] from the own enumerable string-keyed properties of
+    /// `obj`, in the order returned by `Object.entries()`.
+    ///
+    /// Values are not checked against `V`. Propagates any exception thrown by
+    /// a property getter.
+    pub fn to_map<V: JsGeneric>(obj: &Object) -> Result<Map<JsString, V>, JsValue> {
+        let entries = Object::entries_typed(obj.unchecked_ref::<Object<V>>())?;
+        Ok(Map::new_from_entries(&entries))
+    }
+
+    /// Collects the own enumerable string-keyed properties of `obj` into a
+    /// Rust [`BTreeMap`](alloc::collections::BTreeMap), converting the keys
+    /// to `String`.
+    ///
+    /// The entries are read with `Object.entries()`. Propagates any exception
+    /// thrown by a getter or proxy trap.
+    pub fn to_string_map(
+        obj: &Object,
+    ) -> Result<alloc::collections::BTreeMap<String, JsValue>, JsValue> {
+        Ok(Object::entries_typed(obj)?
+            .iter()
+            .map(|entry| {
+                let (key, value) = entry.into_tuple();
+                (String::from(key), value)
+            })
+            .collect())
+    }
+
+    /// Compares the own enumerable string-keyed properties of `old` and
+    /// `new`, returning a null-prototype object of the changes.
+    ///
+    /// The result maps each key that was added to `new`, or whose value
+    /// differs by `Object.is()`, to its new value, and each key that was
+    /// removed from `old` to [`Object::diff_removed`], so that removals can
+    /// be told apart from keys set to `undefined`. Unchanged keys are
+    /// omitted. Propagates any exception thrown by a getter or proxy trap.
+    pub fn diff(old: &Object, new: &Object) -> Result<Object, JsValue> {
+        let old_map = Object::to_map::<JsValue>(old)?;
+        let new_map = Object::to_map::<JsValue>(new)?;
+        // Without a prototype, a `"__proto__"` key is recorded as an own
+        // property rather than hitting the inherited setter.
+        let changes = Object::create(JsValue::NULL.unchecked_ref());
+        for (key, value) in new_map.iter() {
+            let unchanged = old_map.has(&key)
+                && Object::is(&old_map.get_checked(&key).unwrap_or_default(), &value);
+            if !unchanged {
+                Reflect::set_str(&changes, &key, &value)?;
+            }
+        }
+        for (key, _) in old_map.iter() {
+            if !new_map.has(&key) {
+                Reflect::set_str(&changes, &key, &Object::diff_removed())?;
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Returns the value [`Object::diff`] maps removed keys to.
+    ///
+    /// This is the registered symbol `Symbol.for("js_sys.Object.diff.removed")`,
+    /// so it compares equal across calls and realms.
+    pub fn diff_removed() -> Symbol {
+        Symbol::for_("js_sys.Object.diff.removed")
+    }
+
+    /// Looks up a dotted property path such as `"a.b.c"`, starting from
+    /// `obj` and reading one segment at a time with `Reflect.get()`.
+    ///
+    /// Returns `None` if a segment is missing or `undefined`, if a primitive
+    /// is reached before the last segment, or if a getter or proxy trap
+    /// throws.
+    pub fn path_get(obj: &JsValue, path: &str) -> Option<JsValue> {
+        let mut current = obj.clone();
+        for segment in path.split('.') {
+            if !current.is_object() && !current.is_function() {
+                return None;
+            }
+            current = Reflect::get_str(current.unchecked_ref::<Object>(), &segment.into())
+                .ok()
+                .flatten()?;
+        }
+        Some(current)
+    }
+
+    /// Returns the own enumerable string-keyed property names of `obj`.
+    ///
+    /// This is `Object.getOwnPropertyNames()` filtered to the properties
+    /// whose descriptor is enumerable, matching the own properties visited
+    /// by a `for...in` loop. Propagates any exception thrown by a proxy trap.
+    pub fn enumerable_own_keys(obj: &Object) -> Result<Array<JsString>, JsValue> {
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = Object, js_name = getOwnPropertyNames, catch)]
+            fn own_property_names(obj: &Object) -> Result<Array<JsString>, JsValue>;
+        }
+
+        let keys = Array::<JsString>::new_typed();
+        for name in own_property_names(obj)?.iter() {
+            let descriptor = Object::get_own_property_descriptor_str(obj, &name)?;
+            if !descriptor.is_undefined() && descriptor.get_enumerable() == Some(true) {
+                keys.push(&name);
+            }
+        }
+        Ok(keys)
+    }
+}
+
+const MERGE_DEEP_MAX_DEPTH: u32 = 64;
+
+fn merge_deep_at(base: &Object, overlay: &Object, depth: u32) -> Result<Object, JsValue> {
+    if depth >= MERGE_DEEP_MAX_DEPTH {
+        return Err(RangeError::new("Object::merge_deep: maximum depth exceeded").into());
+    }
+    let merged = Object::new();
+    Object::assign(&merged, base);
+    // Copy nested plain objects, so that mutating the result never reaches
+    // back into `base`.
+    for key in Object::keys(base).iter() {
+        let key: JsString = key.unchecked_into();
+        if let Some(value) = Reflect::get_str(&merged, &key)? {
+            if is_plain_object(&value) {
+                let copy = merge_deep_at(value.unchecked_ref(), &Object::new(), depth + 1)?;
+                Reflect::set_str(&merged, &key, &copy)?;
+            }
+        }
+    }
+    for key in Object::keys(overlay).iter() {
+        let key: JsString = key.unchecked_into();
+        let value = Reflect::get_str(overlay, &key)?.unwrap_or_else(JsValue::undefined);
+        let value = match Reflect::get_str(&merged, &key)? {
+            Some(existing) if is_plain_object(&existing) && is_plain_object(&value) => {
+                merge_deep_at(existing.unchecked_ref(), value.unchecked_ref(), depth + 1)?.into()
+            }
+            _ if is_plain_object(&value) => {
+                merge_deep_at(value.unchecked_ref(), &Object::new(), depth + 1)?.into()
+            }
+            _ => value,
+        };
+        Reflect::set_str(&merged, &key, &value)?;
+    }
+    Ok(merged)
+}
+
+fn is_plain_object(value: &JsValue) -> bool {
+    if !value.is_object() || Array::is_array(value) {
+        return false;
+    }
+    let proto = Object::get_prototype_of(value);
+    proto.is_null() || Object::get_prototype_of(&proto).is_null()
 }
 
 impl PartialEq for Object {
@@ -7461,6 +9195,185 @@ extern "C" {
     pub fn revocable(target: &JsValue, handler: &Object) -> Object;
 }
 
+/// A Rust implementation of a [`Proxy`] handler, for use with
+/// [`Proxy::from_handler`].
+///
+/// Each method corresponds to a handler trap. The default implementations
+/// forward the operation to the target via the matching `Reflect` function,
+/// so only the traps of interest need to be overridden. Returning `Err`
+/// throws the error from the intercepted operation.
+///
+/// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy#handler_functions)
+pub trait ProxyHandler {
+    /// Trap for getting a property value.
+    fn get(&self, target: &JsValue, key: &JsValue, receiver: &JsValue) -> Result<JsValue, JsValue> {
+        proxy_reflect::get(target, key, receiver)
+    }
+
+    /// Trap for setting a property value. Returns whether the assignment
+    /// succeeded.
+    fn set(
+        &self,
+        target: &JsValue,
+        key: &JsValue,
+        value: &JsValue,
+        receiver: &JsValue,
+    ) -> Result<bool, JsValue> {
+        proxy_reflect::set(target, key, value, receiver)
+    }
+
+    /// Trap for the `in` operator.
+    fn has(&self, target: &JsValue, key: &JsValue) -> Result<bool, JsValue> {
+        proxy_reflect::has(target, key)
+    }
+
+    /// Trap for the `delete` operator. Returns whether the deletion
+    /// succeeded.
+    fn delete_property(&self, target: &JsValue, key: &JsValue) -> Result<bool, JsValue> {
+        proxy_reflect::delete_property(target, key)
+    }
+
+    /// Trap for `Reflect.ownKeys()`, `Object.keys()` and related operations.
+    fn own_keys(&self, target: &JsValue) -> Result<Array, JsValue> {
+        proxy_reflect::own_keys(target)
+    }
+
+    /// Trap for calling the proxy as a function. Only invoked when the target
+    /// is callable.
+    fn apply(&self, target: &JsValue, this: &JsValue, args: &Array) -> Result<JsValue, JsValue> {
+        proxy_reflect::apply(target, this, args)
+    }
+
+    /// Trap for the `new` operator. Only invoked when the target is a
+    /// constructor, and must return an object.
+    fn construct(
+        &self,
+        target: &JsValue,
+        args: &Array,
+        new_target: &JsValue,
+    ) -> Result<JsValue, JsValue> {
+        proxy_reflect::construct(target, args, new_target)
+    }
+}
+
+// Untyped `Reflect` bindings backing the default `ProxyHandler` traps.
+mod proxy_reflect {
+    use super::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(catch, js_namespace = Reflect)]
+        pub fn get(target: &JsValue, key: &JsValue, receiver: &JsValue)
+            -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch, js_namespace = Reflect)]
+        pub fn set(
+            target: &JsValue,
+            key: &JsValue,
+            value: &JsValue,
+            receiver: &JsValue,
+        ) -> Result<bool, JsValue>;
+
+        #[wasm_bindgen(catch, js_namespace = Reflect)]
+        pub fn has(target: &JsValue, key: &JsValue) -> Result<bool, JsValue>;
+
+        #[wasm_bindgen(catch, js_namespace = Reflect, js_name = deleteProperty)]
+        pub fn delete_property(target: &JsValue, key: &JsValue) -> Result<bool, JsValue>;
+
+        #[wasm_bindgen(catch, js_namespace = Reflect, js_name = ownKeys)]
+        pub fn own_keys(target: &JsValue) -> Result<Array, JsValue>;
+
+        #[wasm_bindgen(catch, js_namespace = Reflect)]
+        pub fn apply(target: &JsValue, this: &JsValue, args: &Array) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch, js_namespace = Reflect)]
+        pub fn construct(
+            target: &JsValue,
+            args: &Array,
+            new_target: &JsValue,
+        ) -> Result<JsValue, JsValue>;
+    }
+}
+
+impl Proxy {
+    /// Creates a [`Proxy`] for `target` whose traps are implemented by
+    /// `handler`.
+    ///
+    /// Every trap of [`ProxyHandler`] is installed on the JS handler object,
+    /// each calling into a closure that shares ownership of `handler`. The
+    /// closures are handed over to the JS garbage collector, so they live as
+    /// long as the proxy is reachable.
+    pub fn from_handler<H: ProxyHandler + 'static>(target: &JsValue, handler: H) -> Proxy {
+        use alloc::rc::Rc;
+        use core::panic::AssertUnwindSafe;
+
+        let handler = Rc::new(handler);
+        let traps = Object::new();
+        let install = |name: &str, trap: JsValue| {
+            Reflect::set_str(&traps, &name.into(), &trap).unwrap_throw();
+        };
+
+        let h = AssertUnwindSafe(Rc::clone(&handler));
+        install(
+            "get",
+            Closure::<dyn FnMut(JsValue, JsValue, JsValue) -> Result<JsValue, JsValue>>::new(
+                move |target, key, receiver| h.get(&target, &key, &receiver),
+            )
+            .into_js_value(),
+        );
+        let h = AssertUnwindSafe(Rc::clone(&handler));
+        install(
+            "set",
+            Closure::<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> Result<bool, JsValue>>::new(
+                move |target, key, value, receiver| h.set(&target, &key, &value, &receiver),
+            )
+            .into_js_value(),
+        );
+        let h = AssertUnwindSafe(Rc::clone(&handler));
+        install(
+            "has",
+            Closure::<dyn FnMut(JsValue, JsValue) -> Result<bool, JsValue>>::new(
+                move |target, key| h.has(&target, &key),
+            )
+            .into_js_value(),
+        );
+        let h = AssertUnwindSafe(Rc::clone(&handler));
+        install(
+            "deleteProperty",
+            Closure::<dyn FnMut(JsValue, JsValue) -> Result<bool, JsValue>>::new(
+                move |target, key| h.delete_property(&target, &key),
+            )
+            .into_js_value(),
+        );
+        let h = AssertUnwindSafe(Rc::clone(&handler));
+        install(
+            "ownKeys",
+            Closure::<dyn FnMut(JsValue) -> Result<Array, JsValue>>::new(move |target| {
+                h.own_keys(&target)
+            })
+            .into_js_value(),
+        );
+        let h = AssertUnwindSafe(Rc::clone(&handler));
+        install(
+            "apply",
+            Closure::<dyn FnMut(JsValue, JsValue, Array) -> Result<JsValue, JsValue>>::new(
+                move |target, this, args| h.apply(&target, &this, &args),
+            )
+            .into_js_value(),
+        );
+        let h = AssertUnwindSafe(handler);
+        install(
+            "construct",
+            Closure::<dyn FnMut(JsValue, Array, JsValue) -> Result<JsValue, JsValue>>::new(
+                move |target, args, new_target| h.construct(&target, &args, &new_target),
+            )
+            .into_js_value(),
+        );
+
+        Proxy::new(target, &traps)
+    }
+}
+
 // RangeError
 #[wasm_bindgen]
 extern "C" {
@@ -7845,6 +9758,84 @@ pub mod Reflect {
             prototype: &JsValue,
         ) -> Result<bool, JsValue>;
     }
+
+    /// Sets several string-keyed properties on `target` in order, as if by
+    /// calling [`Reflect.set()`](set_str) for each entry.
+    ///
+    /// Stops at the first entry that throws or for which `Reflect.set()`
+    /// returns `false` (for example a non-writable property), returning a
+    /// `TypeError` naming the key in the latter case. Entries before the
+    /// failing one remain set.
+    pub fn set_many(target: &JsValue, entries: &[(&str, &JsValue)]) -> Result<(), JsValue> {
+        let target = target.unchecked_ref::<Object>();
+        for (key, value) in entries {
+            if !set_str(target, &JsString::from(*key), *value)? {
+                return Err(TypeError::new(&alloc::format!("cannot set property `{key}`")).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls `target` as a constructor with no arguments, as if by
+    /// `new target()`.
+    ///
+    /// This is [`construct_with_new_target`] with the arguments array built
+    /// internally and `target` as the new target. The `construct1` to
+    /// `construct3` variants take one to three arguments.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct)
+    pub fn construct0(target: &Function) -> Result<Object, JsValue> {
+        construct_with_new_target(target, &Array::new(), target).map(JsCast::unchecked_into)
+    }
+
+    /// Calls `target` as a constructor with one argument, as if by
+    /// `new target(a)`. See [`construct0`].
+    pub fn construct1(target: &Function, a: &JsValue) -> Result<Object, JsValue> {
+        construct_with_new_target(target, &Array::of(core::slice::from_ref(a)), target)
+            .map(JsCast::unchecked_into)
+    }
+
+    /// Calls `target` as a constructor with two arguments, as if by
+    /// `new target(a, b)`. See [`construct0`].
+    pub fn construct2(target: &Function, a: &JsValue, b: &JsValue) -> Result<Object, JsValue> {
+        construct_with_new_target(target, &Array::of(&[a.clone(), b.clone()]), target)
+            .map(JsCast::unchecked_into)
+    }
+
+    /// Calls `target` as a constructor with three arguments, as if by
+    /// `new target(a, b, c)`. See [`construct0`].
+    pub fn construct3(
+        target: &Function,
+        a: &JsValue,
+        b: &JsValue,
+        c: &JsValue,
+    ) -> Result<Object, JsValue> {
+        construct_with_new_target(
+            target,
+            &Array::of(&[a.clone(), b.clone(), c.clone()]),
+            target,
+        )
+        .map(JsCast::unchecked_into)
+    }
+
+    /// Collects the result of [`own_keys`] into a `Vec`.
+    ///
+    /// The keys are in spec order: integer-like keys in ascending numeric
+    /// order, then the remaining string keys in insertion order, then symbol
+    /// keys in insertion order. Integer-like keys are returned as strings.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys)
+    pub fn own_keys_vec(target: &JsValue) -> Result<Vec<JsValue>, JsValue> {
+        Ok(own_keys(target)?.to_vec())
+    }
+
+    /// Like [`own_keys_vec`], but with symbol keys filtered out.
+    pub fn string_keys(target: &JsValue) -> Result<Vec<JsString>, JsValue> {
+        Ok(own_keys(target)?
+            .iter()
+            .filter_map(|key| key.dyn_into().ok())
+            .collect())
+    }
 }
 
 // RegExp
@@ -7870,6 +9861,14 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn exec(this: &RegExp, text: &str) -> Option<RegExpMatchArray>;
 
+    /// The dotAll property indicates whether or not the "s" flag is
+    /// used with the regular expression. dotAll is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/dotAll)
+    #[wasm_bindgen(method, getter, js_name = dotAll)]
+    pub fn dot_all(this: &RegExp) -> bool;
+
     /// The flags property returns a string consisting of the flags of
     /// the current regular expression object.
     ///
@@ -7885,6 +9884,14 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn global(this: &RegExp) -> bool;
 
+    /// The hasIndices property indicates whether or not the "d" flag is
+    /// used with the regular expression. hasIndices is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/hasIndices)
+    #[wasm_bindgen(method, getter, js_name = hasIndices)]
+    pub fn has_indices(this: &RegExp) -> bool;
+
     /// The ignoreCase property indicates whether or not the "i" flag
     /// is used with the regular expression. ignoreCase is a read-only
     /// property of an individual regular expression instance.
@@ -8032,6 +10039,160 @@ extern "C" {
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode)
     #[wasm_bindgen(method, getter)]
     pub fn unicode(this: &RegExp) -> bool;
+
+    /// The unicodeSets property indicates whether or not the "v" flag is
+    /// used with a regular expression. unicodeSets is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicodeSets)
+    #[wasm_bindgen(method, getter, js_name = unicodeSets)]
+    pub fn unicode_sets(this: &RegExp) -> bool;
+}
+
+/// The flags of a [`RegExp`], as returned by [`RegExp::flags_struct`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RegExpFlags {
+    /// The `g` flag.
+    pub global: bool,
+    /// The `i` flag.
+    pub ignore_case: bool,
+    /// The `m` flag.
+    pub multiline: bool,
+    /// The `s` flag.
+    pub dot_all: bool,
+    /// The `u` flag.
+    pub unicode: bool,
+    /// The `y` flag.
+    pub sticky: bool,
+    /// The `d` flag.
+    pub has_indices: bool,
+    /// The `v` flag.
+    pub unicode_sets: bool,
+}
+
+impl RegExp {
+    /// The `RegExp.escape()` static method escapes any potential regex syntax
+    /// characters in a string, and returns a new string that can be safely
+    /// used as a literal pattern for the `RegExp()` constructor.
+    ///
+    /// The native static is used when the engine provides it. Otherwise an
+    /// equivalent Rust implementation of the escaping algorithm is used.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/escape)
+    pub fn escape(text: &str) -> JsString {
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(thread_local_v2, js_namespace = RegExp, js_name = escape)]
+            static NATIVE_ESCAPE: Option<Function>;
+
+            #[wasm_bindgen(js_namespace = RegExp, js_name = escape)]
+            fn native_escape(text: &str) -> JsString;
+        }
+
+        if NATIVE_ESCAPE.with(Option::is_some) {
+            native_escape(text)
+        } else {
+            JsString::from(regexp_escape_fallback(text))
+        }
+    }
+
+    /// Returns the flags of this regular expression as a [`RegExpFlags`],
+    /// read from the individual flag properties rather than by parsing
+    /// [`flags`](RegExp::flags).
+    pub fn flags_struct(&self) -> RegExpFlags {
+        RegExpFlags {
+            global: self.global(),
+            ignore_case: self.ignore_case(),
+            multiline: self.multiline(),
+            dot_all: self.dot_all(),
+            unicode: self.unicode(),
+            sticky: self.sticky(),
+            has_indices: self.has_indices(),
+            unicode_sets: self.unicode_sets(),
+        }
+    }
+
+    /// Replaces each match of this regular expression in `text` with the
+    /// string returned by `f`, like `String.prototype.replace` with a
+    /// replacer function.
+    ///
+    /// The closure receives the `exec`-style match array: the matched text
+    /// followed by the capture groups, with the `index`, `input` and `groups`
+    /// properties set. Only the first match is replaced unless the regular
+    /// expression has the `g` flag, in which case `lastIndex` is reset to `0`
+    /// once done.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace#specifying_a_function_as_the_replacement)
+    pub fn replace_each(&self, text: &str, f: &mut dyn FnMut(Array) -> JsString) -> JsString {
+        // The replacer is called with the match, each capture, the offset,
+        // the input and, when there are named groups, the groups object as
+        // separate arguments. Routing it through a proxy `apply` trap hands
+        // them over as a single array, which is reshaped in place.
+        let mut apply = |_: JsValue, _: JsValue, args: Array| -> JsString {
+            let mut len = args.length();
+            let groups = args.get_unchecked(len - 1);
+            let groups = if groups.is_object() {
+                len -= 1;
+                groups
+            } else {
+                JsValue::UNDEFINED
+            };
+            let input = args.get_unchecked(len - 1);
+            let index = args.get_unchecked(len - 2);
+            args.set_length(len - 2);
+            for (key, value) in [("index", index), ("input", input), ("groups", groups)] {
+                let _ = Reflect::set_str(&args, &key.into(), &value);
+            }
+            f(args)
+        };
+        let apply = ScopedClosure::borrow_mut_assert_unwind_safe(&mut apply);
+        let handler = Object::new();
+        let _ = Reflect::set_str(&handler, &"apply".into(), apply.as_ref());
+        let replacer = Proxy::new(apply.as_ref(), &handler);
+        JsString::from(text).replace_by_pattern_with_function(self, replacer.unchecked_ref())
+    }
+}
+
+// Port of the `EncodeForRegExpEscape` abstract operation, for engines that do
+// not yet ship `RegExp.escape`.
+fn regexp_escape_fallback(text: &str) -> String {
+    use core::fmt::Write;
+
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            // A leading alphanumeric could otherwise extend a preceding
+            // escape sequence such as `\1` or `\x0`.
+            c if i == 0 && c.is_ascii_alphanumeric() => {
+                write!(out, "\\x{:02x}", c as u32).unwrap();
+            }
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            | '/' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\u{b}' => out.push_str("\\v"),
+            '\u{c}' => out.push_str("\\f"),
+            '\r' => out.push_str("\\r"),
+            ',' | '-' | '=' | '<' | '>' | '#' | '&' | '!' | '%' | ':' | ';' | '@' | '~' | '\''
+            | '`' | '"' => {
+                write!(out, "\\x{:02x}", c as u32).unwrap();
+            }
+            // JS `WhiteSpace` and `LineTerminator`. U+0085 is Unicode
+            // whitespace but not JS whitespace.
+            c if (c.is_whitespace() && c != '\u{85}') || c == '\u{feff}' => {
+                if (c as u32) <= 0xff {
+                    write!(out, "\\x{:02x}", c as u32).unwrap();
+                } else {
+                    write!(out, "\\u{:04x}", c as u32).unwrap();
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
 }
 
 // RegExpMatchArray
@@ -8183,6 +10344,10 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn size<T>(this: &Set<T>) -> u32;
 
+    // The set composition methods below only throw when `other` is not a
+    // valid set-like object. They take a `Set<T>`, which always is one, so
+    // they are bound without `catch`.
+
     /// The `union()` method returns a new set containing elements which are in
     /// either or both of this set and the given set.
     ///
@@ -8243,6 +10408,46 @@ impl<T> Iterable for Set<T> {
     type Item = T;
 }
 
+impl<T: FromWasmAbi + JsGeneric> Set<T> {
+    /// Returns an iterator over the values of the set, in insertion order.
+    ///
+    /// Iteration silently stops at the first error raised by the underlying
+    /// JS iterator. Use [`Set::try_iter`] to observe such errors.
+    pub fn iter(&self) -> impl core::iter::Iterator<Item = T> {
+        self.try_iter().map_while(Result::ok)
+    }
+
+    /// Returns an iterator over the values of the set, in insertion order,
+    /// surfacing any error raised by the underlying JS iterator.
+    pub fn try_iter(&self) -> impl core::iter::Iterator<Item = Result<T, JsValue>> {
+        self.values().into_iter()
+    }
+}
+
+// As with `Map`, these collect into a typed `Set<T>`.
+
+impl<T: JsGeneric> core::iter::FromIterator<T> for Set<T> {
+    fn from_iter<I>(iter: I) -> Set<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut out = Set::new_typed();
+        out.extend(iter);
+        out
+    }
+}
+
+impl<T: JsGeneric> core::iter::Extend<T> for Set<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.add(&value);
+        }
+    }
+}
+
 // SetIterator
 #[wasm_bindgen]
 extern "C" {
@@ -9146,6 +11351,20 @@ pub mod JSON {
             space: &JsValue,
         ) -> Result<JsString, JsValue>;
     }
+
+    /// Returns the `length` of `JSON.stringify(value)` without keeping the
+    /// resulting string around.
+    ///
+    /// The length is measured in UTF-16 code units, as with
+    /// [`JsString::length`]. Values that `JSON.stringify` does not serialize
+    /// to a string, such as `undefined` or a function, produce a `TypeError`.
+    pub fn stringify_length(value: &JsValue) -> Result<u32, JsValue> {
+        let json = stringify(value)?;
+        if json.is_undefined() {
+            return Err(TypeError::new("value cannot be serialized to JSON").into());
+        }
+        Ok(json.length())
+    }
 }
 // JsString
 #[wasm_bindgen]
@@ -9831,6 +12050,12 @@ impl JsString {
         val.dyn_ref()
     }
 
+    /// Returns `true` if the string has a `length` of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+
     /// Returns whether this string is a valid UTF-16 string.
     ///
     /// This is useful for learning whether `String::from(..)` will return a
@@ -9892,6 +12117,156 @@ impl JsString {
             None
         }
     }
+
+    /// Returns the UTF-16 code unit at `index` as a one-element string, or
+    /// `None` if `index` is out of range.
+    ///
+    /// Unlike [`char_at`](JsString::char_at), this does not return `""` for
+    /// out-of-range indices.
+    pub fn char_at_checked(&self, index: u32) -> Option<JsString> {
+        if index < self.length() {
+            Some(self.char_at(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the UTF-16 code unit at `index`, or `None` if `index` is out
+    /// of range.
+    ///
+    /// Unlike [`char_code_at`](JsString::char_code_at), this does not return
+    /// `NaN` for out-of-range indices.
+    pub fn char_code_at_checked(&self, index: u32) -> Option<u16> {
+        if index < self.length() {
+            Some(self.char_code_at(index) as u16)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that this string and `other` are equal, ignoring ASCII case,
+    /// like [`str::eq_ignore_ascii_case`].
+    ///
+    /// Only `A`-`Z` and `a`-`z` are folded, so unlike comparing the results
+    /// of `toLowerCase()`, non-ASCII letters must match exactly. The strings
+    /// are compared code unit by code unit without allocating.
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        fn to_ascii_lowercase(unit: u16) -> u16 {
+            match u8::try_from(unit) {
+                Ok(byte) => u16::from(byte.to_ascii_lowercase()),
+                Err(_) => unit,
+            }
+        }
+
+        let mut other = other.encode_utf16();
+        self.iter()
+            .all(|unit| other.next().map(to_ascii_lowercase) == Some(to_ascii_lowercase(unit)))
+            && other.next().is_none()
+    }
+
+    /// Returns a new string with the UTF-16 code units in `[start, end)`
+    /// replaced by `replacement`, like [`String::replace_range`].
+    ///
+    /// Indices past the end of the string are clamped to its length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`.
+    pub fn replace_range(&self, start: u32, end: u32, replacement: &str) -> JsString {
+        assert!(start <= end, "range start must not exceed its end");
+        self.slice(0, start)
+            .concat(&JsString::from(replacement))
+            .concat(&self.slice(end, self.length()))
+    }
+
+    /// Returns the UTF-8 encoding of this string as a byte vector.
+    ///
+    /// This goes through the same conversion as `String::from`, so it is
+    /// lossy: any unpaired surrogate in the JS string is replaced with the
+    /// UTF-8 encoding of U+FFFD REPLACEMENT CHARACTER (`EF BF BD`). Use
+    /// [`JsString::is_valid_utf16`] to check whether the conversion is
+    /// lossless.
+    pub fn to_utf8_bytes(&self) -> Vec<u8> {
+        String::from(self).into_bytes()
+    }
+
+    /// Splits the string on `separator`, returning at most `n` substrings.
+    ///
+    /// This is [`split_limit`](JsString::split_limit) with a typed result.
+    /// It follows the JS limit semantics, which differ from [`str::splitn`]:
+    /// the split is truncated after `n` entries and the remainder of the
+    /// string is discarded, rather than being kept in the last entry. For
+    /// example, splitting `"a,b,c,d"` on `","` with `n = 2` yields
+    /// `["a", "b"]`, whereas Rust would yield `["a", "b,c,d"]`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
+    pub fn splitn(&self, n: u32, separator: &str) -> Array<JsString> {
+        self.split_limit(separator, n).unchecked_into()
+    }
+
+    /// Splits the string on the first occurrence of `separator`, returning
+    /// the parts before and after it, or `None` if `separator` is absent.
+    ///
+    /// This mirrors [`str::split_once`].
+    pub fn split_once(&self, separator: &str) -> Option<(JsString, JsString)> {
+        let index = self.index_of(separator, 0);
+        if index < 0 {
+            return None;
+        }
+        let index = index as u32;
+        let separator_len = separator.encode_utf16().count() as u32;
+        Some((
+            self.slice(0, index),
+            self.slice(index + separator_len, self.length()),
+        ))
+    }
+
+    /// Returns an iterator over the lines of the string, mirroring
+    /// [`str::lines`].
+    ///
+    /// Lines are split on `\n`, and a trailing `\r` is stripped from each
+    /// line. A final line ending does not produce an empty trailing line.
+    /// Lines are sliced lazily, without first collecting them into a JS
+    /// array.
+    pub fn lines(&self) -> impl core::iter::Iterator<Item = JsString> + '_ {
+        let len = self.length();
+        let mut start = 0;
+        iter::from_fn(move || {
+            if start >= len {
+                return None;
+            }
+            let newline = self.index_of("\n", start as i32);
+            let end = if newline < 0 { len } else { newline as u32 };
+            let mut line_end = end;
+            if line_end > start && self.char_code_at(line_end - 1) == f64::from(b'\r') {
+                line_end -= 1;
+            }
+            let line = self.slice(start, line_end);
+            start = end + 1;
+            Some(line)
+        })
+    }
+
+    /// Splits the string into its extended grapheme clusters, i.e. the
+    /// user-perceived characters, such as a flag or a family emoji.
+    ///
+    /// This uses a default `Intl.Segmenter`, whose granularity is
+    /// `"grapheme"`, for the current locale.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment)
+    pub fn graphemes(&self) -> Vec<JsString> {
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = Array, js_name = from)]
+            fn collect_segments(segments: &Intl::Segments) -> Array<Intl::SegmentData>;
+        }
+
+        let segments = Intl::Segmenter::default().segment_js_string(self);
+        collect_segments(&segments)
+            .iter()
+            .map(|data| data.segment())
+            .collect()
+    }
 }
 
 impl PartialEq<str> for JsString {
@@ -9944,6 +12319,14 @@ impl From<char> for JsString {
     }
 }
 
+/// Copies the string into Rust memory, transcoding it from UTF-16 to UTF-8 in
+/// a single pass.
+///
+/// The characters of a JS string live in the JS heap rather than in wasm
+/// linear memory, so there is nothing for a `&str` or `Cow::Borrowed` to
+/// point into: every conversion allocates. Lone surrogates are replaced with
+/// U+FFFD REPLACEMENT CHARACTER, so use [`JsString::is_valid_utf16`] first if
+/// the conversion must be lossless.
 impl<'a> From<&'a JsString> for String {
     fn from(s: &'a JsString) -> Self {
         s.obj.as_string().unwrap_throw()
@@ -10120,6 +12503,17 @@ extern "C" {
     pub fn value_of(this: &Symbol) -> Symbol;
 }
 
+impl Symbol {
+    /// Looks up or creates a symbol in the global symbol registry for each of
+    /// `keys`, as if by calling [`Symbol::for_`] on each one in turn.
+    ///
+    /// Symbols for the same key are identical, so calling this again with
+    /// the same keys returns the same symbols.
+    pub fn registered(keys: &[&str]) -> Vec<Symbol> {
+        keys.iter().map(|key| Symbol::for_(key)).collect()
+    }
+}
+
 #[allow(non_snake_case)]
 pub mod Intl {
     use super::*;
@@ -12288,6 +14682,9 @@ pub mod Intl {
         #[wasm_bindgen(method, js_class = "Intl.Segmenter")]
         pub fn segment(this: &Segmenter, input: &str) -> Segments;
 
+        #[wasm_bindgen(method, js_class = "Intl.Segmenter", js_name = segment)]
+        pub(crate) fn segment_js_string(this: &Segmenter, input: &JsString) -> Segments;
+
         /// Returns an object with properties reflecting the options used.
         ///
         /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/resolvedOptions)
@@ -12436,7 +14833,7 @@ pub mod Intl {
 
         /// Returns the display name for the given code.
         ///
-        /// Returns `undefined` if fallback is "none" and no name is available.
+        /// Returns `None` if fallback is `"none"` and no name is available.
         ///
         /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of)
         #[wasm_bindgen(method, js_class = "Intl.DisplayNames")]
@@ -12605,7 +15002,8 @@ pub mod Intl {
         #[wasm_bindgen(method, js_class = "Intl.Locale", js_name = getTextInfo, catch)]
         pub fn get_text_info(this: &Locale) -> Result<TextInfo, JsValue>;
 
-        /// Returns a new Locale with the specified calendar.
+        /// Returns a new Locale with the most likely subtags for the language,
+        /// script and region added.
         ///
         /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/maximize)
         #[wasm_bindgen(method, js_class = "Intl.Locale")]
@@ -13320,6 +15718,10 @@ extern "C" {
     /// returned promise will "follow" that thenable, adopting its eventual
     /// state; otherwise the returned promise will be fulfilled with the value.
     ///
+    /// The returned promise is typed by the resolution of `obj`, so
+    /// `Promise::resolve(&Number::from(1))` is a `Promise<Number>`. Use
+    /// [`Promise::reject_typed`] for a typed rejected promise.
+    ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve)
     #[wasm_bindgen(static_method_of = Promise, js_name = resolve)]
     pub fn resolve<U: Promising>(obj: &U) -> Promise<U::Resolution>;
@@ -13418,8 +15820,34 @@ extern "C" {
     /// `catch()` handlers.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally)
+    #[cfg(not(js_sys_unstable_apis))]
     #[wasm_bindgen(method)]
     pub fn finally<T>(this: &Promise<T>, cb: &ScopedClosure<dyn FnMut()>) -> Promise<JsValue>;
+
+    /// The `finally()` method returns a `Promise`. When the promise is settled,
+    /// whether fulfilled or rejected, the specified callback function is
+    /// executed. This provides a way for code that must be executed once the
+    /// `Promise` has been dealt with to be run whether the promise was
+    /// fulfilled successfully or rejected.
+    ///
+    /// The returned promise settles with the same value or reason as the
+    /// original, so the resolved type `T` is preserved.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally)
+    #[cfg(js_sys_unstable_apis)]
+    #[wasm_bindgen(method)]
+    pub fn finally<'a, T>(this: &Promise<T>, cb: &ScopedClosure<'a, dyn FnMut()>) -> Promise<T>;
+
+    // Next major: deprecate
+    /// Same as `finally`, but preserving the resolved type `T` of the original
+    /// promise, since `finally()` passes the settled value through unchanged.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally)
+    #[wasm_bindgen(method, js_name = finally)]
+    pub fn finally_typed<'a, T>(
+        this: &Promise<T>,
+        cb: &ScopedClosure<'a, dyn FnMut()>,
+    ) -> Promise<T>;
 }
 
 impl<T: JsGeneric> Promising for Promise<T> {
@@ -13566,6 +15994,59 @@ impl Promise {
     pub fn all_settled_tuple<T: PromiseTuple>(promises: T) -> Promise<T::Settled> {
         promises.all_settled()
     }
+
+    /// Joins two differently-typed promises into one resolving to a typed
+    /// `ArrayTuple<(A, B)>`.
+    ///
+    /// Shorthand for [`Promise::all_tuple`] over borrowed promises.
+    #[inline]
+    pub fn join2<A: JsGeneric, B: JsGeneric>(
+        a: &Promise<A>,
+        b: &Promise<B>,
+    ) -> Promise<ArrayTuple<(A, B)>> {
+        // `A` and `B` need not be `Clone`, so duplicate the handles directly.
+        let a: Promise<A> = AsRef::<JsValue>::as_ref(a).clone().unchecked_into();
+        let b: Promise<B> = AsRef::<JsValue>::as_ref(b).clone().unchecked_into();
+        Promise::all_tuple((a, b))
+    }
+
+    /// Returns a promise that resolves with the value of the first of
+    /// `promises` to fulfill, ignoring rejections unless all of them reject,
+    /// in which case it rejects with an `AggregateError`.
+    ///
+    /// This is `Promise.any()` over a typed array, preserving `T`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any)
+    #[inline]
+    pub fn first_ok<T: JsGeneric>(promises: &Array<Promise<T>>) -> Promise<T> {
+        Promise::any_iterable(promises)
+    }
+
+    /// Calls `factory` and retries with a fresh promise from it each time the
+    /// previous one rejects, up to `attempts` calls in total.
+    ///
+    /// The returned promise resolves with the first successful result, or
+    /// rejects with the last rejection once all attempts are exhausted. At
+    /// least one attempt is always made, even if `attempts` is 0.
+    ///
+    /// The attempts are driven by a Rust future (see
+    /// [`future_to_promise_typed`](crate::futures::future_to_promise_typed)),
+    /// so no JS callbacks need to be kept alive by the caller.
+    pub fn retry<F>(mut factory: F, attempts: u32) -> Promise
+    where
+        F: FnMut() -> Promise + 'static,
+    {
+        crate::futures::future_to_promise_typed(async move {
+            let mut remaining = attempts.max(1);
+            loop {
+                match crate::futures::JsFuture::from(factory()).await {
+                    Ok(value) => return Ok(value),
+                    Err(err) if remaining == 1 => return Err(err),
+                    Err(_) => remaining -= 1,
+                }
+            }
+        })
+    }
 }
 
 /// Returns a handle to the global scope object.
@@ -13753,6 +16234,14 @@ impl Default for Float16Array {
 
 impl TypedArray for Float16Array {}
 
+impl TypedArrayView for Float16Array {
+    const BYTES_PER_ELEMENT: usize = 2;
+
+    fn view_of(buffer: &ArrayBuffer) -> Self {
+        Float16Array::new(buffer)
+    }
+}
+
 impl Float16Array {
     fn as_uint16_view(&self) -> Uint16Array {
         let buffer = self.buffer();
@@ -14129,6 +16618,14 @@ macro_rules! arrays {
 
         impl TypedArray for $name {}
 
+        impl TypedArrayView for $name {
+            const BYTES_PER_ELEMENT: usize = core::mem::size_of::<$ty>();
+
+            fn view_of(buffer: &ArrayBuffer) -> Self {
+                $name::new(buffer)
+            }
+        }
+
 
     )*);
 }
@@ -1,6 +1,7 @@
 use js_sys::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
 
 #[wasm_bindgen_test]
@@ -61,3 +62,33 @@ fn name() {
     let base_error: &Error = error.dyn_ref().unwrap();
     assert_eq!(JsValue::from(base_error.name()), "AggregateError");
 }
+
+#[wasm_bindgen_test]
+fn errors_round_trip() {
+    let inner = [Error::new("a"), Error::new("b"), Error::new("c")];
+    let values: Vec<JsValue> = inner.iter().cloned().map(JsValue::from).collect();
+    let error = AggregateError::new(&values);
+    let errors = error.errors();
+    assert_eq!(errors.length(), 3);
+    for (i, expected) in inner.iter().enumerate() {
+        assert_eq!(errors.get_unchecked(i as u32), JsValue::from(expected));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn from_promise_any() {
+    let rejected: Array<Promise<Number>> = Array::new_typed();
+    for message in ["a", "b", "c"] {
+        rejected.push(&Promise::reject_typed(&Error::new(message).into()));
+    }
+    let err = JsFuture::from(Promise::any_iterable(&rejected))
+        .await
+        .unwrap_err();
+    let error: AggregateError = err.dyn_into().unwrap();
+    let messages: Vec<String> = error
+        .errors()
+        .iter()
+        .map(|e| String::from(e.unchecked_into::<Error>().message()))
+        .collect();
+    assert_eq!(messages, ["a", "b", "c"]);
+}
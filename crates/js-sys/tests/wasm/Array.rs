@@ -220,6 +220,352 @@ fn iter() {
     assert_eq!(iter.next_back(), None);
 }
 
+#[wasm_bindgen_test]
+fn is_empty() {
+    assert!(Array::<Number>::new_typed().is_empty());
+    assert!(!js_array![Number; 1u32].is_empty());
+}
+
+#[wasm_bindgen_test]
+fn count_where() {
+    let array = js_array![Number; 1u32, 2u32, 3u32, 4u32];
+    assert_eq!(
+        array.count_where(&mut |n: Number| n.value_of() % 2.0 == 0.0),
+        2
+    );
+
+    let empty: Array<Number> = Array::new_typed();
+    assert_eq!(empty.count_where(&mut |_| true), 0);
+}
+
+#[wasm_bindgen_test]
+fn position() {
+    let array = js_array![Number; 1u32, 2u32, 3u32];
+    assert_eq!(array.position(&mut |n: Number| n.value_of() > 2.0), Some(2));
+    assert_eq!(array.position(&mut |n: Number| n.value_of() > 3.0), None);
+}
+
+#[wasm_bindgen_test]
+fn rposition() {
+    let array = js_array![Number; 1u32, 2u32, 3u32, 4u32, 5u32];
+    assert_eq!(
+        array.rposition(&mut |n: Number| n.value_of() % 2.0 == 0.0),
+        Some(3)
+    );
+    assert_eq!(array.rposition(&mut |n: Number| n.value_of() > 5.0), None);
+}
+
+#[wasm_bindgen_test]
+fn split_at() {
+    let array = js_array![Number; 1u32, 2u32, 3u32, 4u32];
+    let (left, right) = array.split_at(2);
+    assert_eq!(to_rust(&left), array![Number; 1u32, 2u32]);
+    assert_eq!(to_rust(&right), array![Number; 3u32, 4u32]);
+    assert_eq!(array.length(), 4);
+
+    let (left, right) = array.split_at(10);
+    assert_eq!(left.length(), 4);
+    assert_eq!(right.length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn rchunks() {
+    let array = js_array![Number; 1u32, 2u32, 3u32, 4u32, 5u32];
+    let chunks: Vec<Vec<Number>> = array.rchunks(2).map(|chunk| to_rust(&chunk)).collect();
+    assert_eq!(
+        chunks,
+        vec![
+            array![Number; 4u32, 5u32],
+            array![Number; 2u32, 3u32],
+            array![Number; 1u32]
+        ]
+    );
+    assert_eq!(Array::<Number>::new_typed().rchunks(2).count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn scan() {
+    let array = js_array![Number; 1u32, 2u32, 3u32];
+    let sums = array.scan(Number::from(0), &mut |acc, n| {
+        *acc = Number::from(acc.value_of() + n.value_of());
+        acc.clone()
+    });
+    assert_eq!(to_rust(&sums), array![Number; 1u32, 3u32, 6u32]);
+
+    let empty: Array<Number> = Array::new_typed();
+    assert_eq!(
+        empty
+            .scan(Number::from(0), &mut |acc, _| acc.clone())
+            .length(),
+        0
+    );
+}
+
+#[wasm_bindgen_test]
+fn interleave() {
+    let numbers = js_array![JsValue; 1, 2, 3];
+    let letters = js_array![JsValue; "a", "b"];
+    assert_eq!(
+        to_rust(&numbers.interleave(&letters)),
+        array![JsValue; 1, "a", 2, "b", 3]
+    );
+    assert_eq!(to_rust(&letters.interleave(&numbers)).len(), 5);
+}
+
+#[wasm_bindgen_test]
+fn run_length_encode() {
+    let array = js_array![JsString; "a", "a", "b"];
+    let runs: Vec<(JsString, Number)> = to_rust(&array.run_length_encode(&mut |a, b| a == b))
+        .into_iter()
+        .map(|t| t.into_tuple())
+        .collect();
+    assert_eq!(
+        runs,
+        vec![("a".into(), 2u32.into()), ("b".into(), 1u32.into())]
+    );
+    assert_eq!(
+        Array::<JsString>::new_typed()
+            .run_length_encode(&mut |a, b| a == b)
+            .length(),
+        0
+    );
+}
+
+#[wasm_bindgen_test]
+fn chunk_by_key() {
+    let words = js_array![JsString; "apple", "bob", "avocado", "banana", "cherry"];
+    let groups = words.chunk_by_key(&mut |word| word.slice(0, 1));
+    assert_eq!(groups.size(), 3);
+    let keys: Vec<JsString> = groups.iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, ["a", "b", "c"]);
+    let a = groups.get_checked(&"a".into()).unwrap();
+    assert_eq!(to_rust(&a), array![JsString; "apple", "avocado"]);
+    let b = groups.get_checked(&"b".into()).unwrap();
+    assert_eq!(to_rust(&b), array![JsString; "bob", "banana"]);
+}
+
+#[wasm_bindgen_test]
+fn first_duplicate() {
+    let array = js_array![Number; 1u32, 2u32, 3u32, 2u32];
+    assert_eq!(array.first_duplicate(&mut |a, b| a == b), Some((1, 3)));
+    let array = js_array![Number; 1u32, 2u32, 3u32];
+    assert_eq!(array.first_duplicate(&mut |a, b| a == b), None);
+}
+
+#[wasm_bindgen_test]
+fn split_first_last() {
+    let array = js_array![Number; 1u32, 2u32, 3u32];
+    let (first, rest) = array.split_first().unwrap();
+    assert_eq!(first, 1);
+    assert_eq!(to_rust(&rest), array![Number; 2u32, 3u32]);
+    let (last, rest) = array.split_last().unwrap();
+    assert_eq!(last, 3);
+    assert_eq!(to_rust(&rest), array![Number; 1u32, 2u32]);
+
+    let empty = Array::<Number>::new_typed();
+    assert!(empty.split_first().is_none());
+    assert!(empty.split_last().is_none());
+}
+
+#[wasm_bindgen_test]
+fn intersperse() {
+    let zero = Number::from(0);
+    let array = js_array![Number; 1u32, 2u32, 3u32];
+    assert_eq!(
+        to_rust(&array.intersperse(&zero)),
+        array![Number; 1u32, 0u32, 2u32, 0u32, 3u32]
+    );
+    let single = js_array![Number; 1u32];
+    assert_eq!(to_rust(&single.intersperse(&zero)), array![Number; 1u32]);
+    assert!(Array::<Number>::new_typed().intersperse(&zero).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn try_for_each_rust() {
+    #[derive(Debug, PartialEq)]
+    struct TooBig(u32);
+
+    let array = js_array![Number; 1u32, 2u32, 30u32, 4u32];
+    let mut seen = Vec::new();
+    let result = array.try_for_each_rust(&mut |index, n| {
+        seen.push(index);
+        if n.value_of() > 10.0 {
+            Err(TooBig(index))
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(result, Err(TooBig(2)));
+    assert_eq!(seen, [0, 1, 2]);
+
+    let result: Result<(), TooBig> = array.try_for_each_rust(&mut |_, _| Ok(()));
+    assert!(result.is_ok());
+}
+
+#[wasm_bindgen_test]
+fn to_object() {
+    let array = js_array![JsString; "a", "b"];
+    let obj = array.to_object();
+    assert_eq!(JSON::stringify(&obj).unwrap(), r#"{"0":"a","1":"b"}"#);
+    assert!(!Array::is_array(&obj));
+}
+
+#[wasm_bindgen_test]
+fn to_vec_via() {
+    let array = js_array![Number; 1.5, -2.0, 3.75];
+    let ints = array.to_vec_via(&mut |n| n.value_of() as i32);
+    assert_eq!(ints, [1, -2, 3]);
+    assert!(Array::<Number>::new_typed()
+        .to_vec_via(&mut |n| n.value_of())
+        .is_empty());
+}
+
+#[wasm_bindgen_test]
+fn try_map_rust() {
+    use std::num::ParseFloatError;
+
+    let mut parse = |s: JsString| -> Result<Number, ParseFloatError> {
+        String::from(s).parse::<f64>().map(Number::from)
+    };
+
+    let array = js_array![JsString; "1", "2.5"];
+    let numbers = array.try_map_rust(&mut parse).unwrap();
+    assert_eq!(to_rust(&numbers), array![Number; 1.0, 2.5]);
+
+    let array = js_array![JsString; "1", "two", "3"];
+    assert!(array.try_map_rust(&mut parse).is_err());
+}
+
+#[wasm_bindgen_test]
+fn with_capacity() {
+    let arr = Array::<Number>::with_capacity(3);
+    assert_eq!(arr.length(), 3);
+    assert_eq!(arr.get_checked(1), None);
+
+    for i in 0..3 {
+        arr.set_ref(i, &Number::from(i * 10));
+    }
+    assert_eq!(arr.length(), 3);
+    assert_eq!(to_rust(&arr), array![Number; 0, 10, 20]);
+}
+
+#[wasm_bindgen_test]
+fn cycle_take() {
+    let array = js_array![Number; 1u32, 2u32];
+    assert_eq!(
+        to_rust(&array.cycle_take(5)),
+        array![Number; 1u32, 2u32, 1u32, 2u32, 1u32]
+    );
+    assert_eq!(to_rust(&array.cycle_take(1)), array![Number; 1u32]);
+    assert!(array.cycle_take(0).is_empty());
+    assert!(Array::<Number>::new_typed().cycle_take(0).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn contains() {
+    let array = js_array![Number; 1, 2, 3];
+    assert!(array.contains(&Number::from(2)));
+    assert!(!array.contains(&Number::from(4)));
+
+    let with_nan = js_array![Number; f64::NAN];
+    assert!(with_nan.contains(&Number::from(f64::NAN)));
+
+    let obj = Object::new();
+    let objects = js_array![Object; obj.clone()];
+    assert!(objects.contains(&obj));
+    assert!(!objects.contains(&Object::new()));
+}
+
+#[wasm_bindgen_test]
+fn to_number_vec() {
+    let numbers = js_array![Number; 1.5, 2, -3];
+    assert_eq!(numbers.to_f64_vec(), [1.5, 2.0, -3.0]);
+    assert_eq!(numbers.to_number_vec().unwrap(), [1.5, 2.0, -3.0]);
+
+    let mixed = js_array![JsValue; 1, "2", 3];
+    assert_eq!(mixed.to_number_vec().unwrap_err(), "2");
+}
+
+#[wasm_bindgen_test]
+fn try_collect() {
+    let strings = js_array![JsString; "a", "b"];
+    assert_eq!(strings.try_collect::<String>().unwrap(), ["a", "b"]);
+
+    let numbers = js_array![Number; 1, 2];
+    let collected: Vec<f64> = numbers.try_collect().unwrap();
+    assert_eq!(collected, [1.0, 2.0]);
+
+    let mixed = js_array![JsValue; "a", 1, "b"];
+    assert_eq!(mixed.try_collect::<String>().unwrap_err(), 1);
+}
+
+#[wasm_bindgen_test]
+fn binary_insert() {
+    let array = js_array![Number; 1, 3, 5];
+    let mut cmp = |a: &Number, b: &Number| a.value_of().total_cmp(&b.value_of());
+    assert_eq!(array.binary_insert(&Number::from(4), &mut cmp), 2);
+    assert_eq!(to_rust(&array), array![Number; 1, 3, 4, 5]);
+    assert_eq!(array.binary_insert(&Number::from(0), &mut cmp), 0);
+    assert_eq!(array.binary_insert(&Number::from(9), &mut cmp), 5);
+    assert_eq!(array.binary_insert(&Number::from(3), &mut cmp), 3);
+    assert_eq!(to_rust(&array), array![Number; 0, 1, 3, 3, 4, 5, 9]);
+
+    let empty: Array<Number> = Array::new_typed();
+    assert_eq!(empty.binary_insert(&Number::from(1), &mut cmp), 0);
+    assert_eq!(to_rust(&empty), array![Number; 1]);
+}
+
+#[wasm_bindgen_test]
+fn take_while_skip_while() {
+    let array = js_array![Number; 2u32, 4u32, 5u32, 6u32];
+    let mut is_even = |n: &Number| n.value_of() % 2.0 == 0.0;
+    assert_eq!(
+        to_rust(&array.take_while(&mut is_even)),
+        array![Number; 2u32, 4u32]
+    );
+    assert_eq!(
+        to_rust(&array.skip_while(&mut is_even)),
+        array![Number; 5u32, 6u32]
+    );
+    assert!(array.take_while(&mut |_| false).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn resize_with() {
+    let array = js_array![Number; 1u32, 2u32];
+    array.resize_with(4, &mut |index| index.into());
+    assert_eq!(to_rust(&array), array![Number; 1u32, 2u32, 2u32, 3u32]);
+
+    array.resize_with(1, &mut |_| unreachable!());
+    assert_eq!(to_rust(&array), array![Number; 1u32]);
+}
+
+#[wasm_bindgen_test]
+fn sum_product_f64() {
+    let array = js_array![Number; 1u32, 2u32, 3u32];
+    assert_eq!(array.sum_f64(), 6.0);
+    assert_eq!(array.product_f64(), 6.0);
+
+    let empty: Array<Number> = Array::new_typed();
+    assert_eq!(empty.sum_f64(), 0.0);
+    assert_eq!(empty.product_f64(), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn dedup() {
+    let array = js_array![JsString; "a", "a", "b"];
+    array.dedup();
+    assert_eq!(to_rust(&array), array![JsString; "a", "b"]);
+
+    let array = js_array![Number; 1u32, 1u32, 2u32, 2u32, 1u32];
+    array.dedup();
+    assert_eq!(to_rust(&array), array![Number; 1u32, 2u32, 1u32]);
+
+    let empty: Array<Number> = Array::new_typed();
+    empty.dedup();
+    assert_eq!(empty.length(), 0);
+}
+
 #[wasm_bindgen_test]
 fn new_with_length() {
     let array: Array<JsValue> = Array::new_with_length(5);
@@ -397,6 +743,19 @@ fn sort() {
     assert_eq!(to_rust(&sorted), array![Number; 1, 2, 3, 6]);
 }
 
+#[wasm_bindgen_test]
+fn sort_numbers() {
+    let array = js_array![Number; 10, 2, 1];
+    let sorted = array.sort_numbers();
+    assert_eq!(to_rust(&sorted), array![Number; 1, 2, 10]);
+    assert_eq!(to_rust(&array), array![Number; 1, 2, 10]);
+
+    let array = js_array![Number; f64::NAN, 3.5, -1.0];
+    let sorted = to_rust(&array.sort_numbers());
+    assert_eq!(sorted[..2], array![Number; -1.0, 3.5]);
+    assert!(Number::is_nan(&sorted[2]));
+}
+
 #[wasm_bindgen_test]
 #[allow(clippy::cmp_owned)]
 fn some() {
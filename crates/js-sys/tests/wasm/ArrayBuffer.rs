@@ -16,6 +16,18 @@ fn byte_length() {
     assert_eq!(buf.byte_length(), 42);
 }
 
+#[wasm_bindgen_test]
+fn view() {
+    let buffer = ArrayBuffer::new(8);
+    Uint8Array::new(&buffer).copy_from(&[0, 0, 0x80, 0x3f, 0, 0, 0x20, 0x40]);
+
+    let floats: Float32Array = buffer.view();
+    assert_eq!(floats.length(), 2);
+    assert_eq!(floats.get_index(0), 1.0);
+    assert_eq!(floats.get_index(1), 2.5);
+    assert_eq!(buffer.view::<Uint16Array>().length(), 4);
+}
+
 #[wasm_bindgen_test]
 fn is_view() {
     let x = Uint8Array::new(&JsValue::from(42));
@@ -0,0 +1,37 @@
+use js_sys::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn store_load_u8() {
+    let array = Uint8Array::new_with_length(4);
+    assert_eq!(Atomics::store_u8(&array, 1, 200).unwrap(), 200);
+    assert_eq!(Atomics::load_u8(&array, 1).unwrap(), 200);
+    assert_eq!(array.get_index(1), 200);
+    assert!(Atomics::load_u8(&array, 4).is_err());
+}
+
+#[wasm_bindgen_test]
+fn store_load_i16() {
+    let array = Int16Array::new_with_length(2);
+    assert_eq!(Atomics::store_i16(&array, 0, -1234).unwrap(), -1234);
+    assert_eq!(Atomics::load_i16(&array, 0).unwrap(), -1234);
+}
+
+#[wasm_bindgen_test]
+fn read_modify_write_u32() {
+    let array = Uint32Array::new_with_length(1);
+    let big = i32::MAX as u32 + 10;
+    array.set_index(0, big);
+
+    assert_eq!(Atomics::add_u32(&array, 0, 5).unwrap(), big);
+    assert_eq!(Atomics::sub_u32(&array, 0, 2).unwrap(), big + 5);
+    assert_eq!(Atomics::or_u32(&array, 0, 1).unwrap(), big + 3);
+    assert_eq!(Atomics::and_u32(&array, 0, u32::MAX).unwrap(), big + 4);
+    assert_eq!(Atomics::xor_u32(&array, 0, 0).unwrap(), big + 4);
+    assert_eq!(Atomics::exchange_u32(&array, 0, u32::MAX).unwrap(), big + 4);
+    assert_eq!(
+        Atomics::compare_exchange_u32(&array, 0, u32::MAX, 7).unwrap(),
+        u32::MAX
+    );
+    assert_eq!(array.get_index(0), 7);
+}
@@ -1,4 +1,4 @@
-use js_sys::BigInt;
+use js_sys::{BigInt, BigIntParseError};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::wasm_bindgen_test;
 
@@ -41,3 +41,47 @@ fn eq() {
     assert_eq!(BigInt::from(-3i64), -3i128);
     assert_eq!(BigInt::from(-3i64), -3isize);
 }
+
+#[wasm_bindgen_test]
+fn try_from_str() {
+    assert_eq!(BigInt::try_from("123").unwrap(), BigInt::from(123));
+    assert_eq!(BigInt::try_from(""), Err(BigIntParseError::Empty));
+    assert_eq!(BigInt::try_from("12x"), Err(BigIntParseError::InvalidDigit));
+}
+
+#[wasm_bindgen_test]
+fn dyn_into() {
+    let big: BigInt = JsValue::from(BigInt::from(7)).dyn_into().unwrap();
+    assert_eq!(big, BigInt::from(7));
+    let err = JsValue::from(7).dyn_into::<BigInt>().unwrap_err();
+    assert_eq!(err, 7);
+}
+
+#[wasm_bindgen_test]
+fn to_grouped_string() {
+    assert_eq!(BigInt::from(1234567).to_grouped_string(3, ','), "1,234,567");
+    assert_eq!(BigInt::from(-123456).to_grouped_string(3, ','), "-123,456");
+    assert_eq!(BigInt::from(999).to_grouped_string(3, ','), "999");
+    assert_eq!(BigInt::from(0).to_grouped_string(3, ','), "0");
+    assert_eq!(
+        BigInt::from(12345678).to_grouped_string(4, '_'),
+        "1234_5678"
+    );
+    assert_eq!(BigInt::from(-1234).to_grouped_string(0, ','), "-1234");
+}
+
+#[wasm_bindgen_test]
+fn to_f64_lossy() {
+    let max_safe = BigInt::from(9007199254740991i64);
+    assert!(max_safe.fits_in_safe_integer());
+    assert_eq!(max_safe.to_f64_lossy(), 9007199254740991.0);
+    assert!((-&max_safe).fits_in_safe_integer());
+
+    let unsafe_int = BigInt::from(9007199254740993i64);
+    assert!(!unsafe_int.fits_in_safe_integer());
+    assert!(!(-&unsafe_int).fits_in_safe_integer());
+    assert_eq!(unsafe_int.to_f64_lossy(), 9007199254740992.0);
+
+    let huge = BigInt::from(2).pow(&BigInt::from(1100));
+    assert!(huge.to_f64_lossy().is_infinite());
+}
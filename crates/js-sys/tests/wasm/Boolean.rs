@@ -44,3 +44,13 @@ fn boolean_inheritance_unstable() {
     assert!(!b.is_instance_of::<Object>());
     assert_eq!(b.value_of(), true);
 }
+
+#[wasm_bindgen_test]
+fn dyn_into() {
+    let b: Boolean = wasm_bindgen::JsValue::TRUE.dyn_into().unwrap();
+    assert!(b.value_of());
+    let err = wasm_bindgen::JsValue::from(1)
+        .dyn_into::<Boolean>()
+        .unwrap_err();
+    assert_eq!(err, 1);
+}
@@ -88,6 +88,21 @@ fn test() {
     assert_eq!(bytes.get_index(2), 42);
 }
 
+#[wasm_bindgen_test]
+fn length_tracking() {
+    let buf = ArrayBuffer::new_with_options(8, &ArrayBufferOptions::new(32));
+    let view = DataView::new_length_tracking(&buf, 2);
+    assert_eq!(view.byte_length(), 6);
+
+    buf.resize(20).unwrap();
+    assert_eq!(view.byte_length(), 18);
+    view.set_uint8(17, 42);
+    assert_eq!(view.get_uint8(17), 42);
+
+    buf.resize(4).unwrap();
+    assert_eq!(view.byte_length(), 2);
+}
+
 #[wasm_bindgen_test]
 fn dataview_inheritance() {
     let bytes = Int8Array::new(&JsValue::from(10));
@@ -100,3 +115,22 @@ fn dataview_inheritance() {
     assert!(v.is_instance_of::<Object>());
     let _: &Object = v.as_ref();
 }
+
+#[wasm_bindgen_test]
+fn reader() {
+    let bytes = Uint8Array::new_with_length(8);
+    bytes.copy_from(&[0x78, 0x56, 0x34, 0x12, 0xcd, 0xab, 0xff, 0x00]);
+    let view = DataView::new(&bytes.buffer(), 0, 8);
+
+    let mut reader = DataViewReader::new(&view);
+    assert_eq!(reader.read_u32_le().unwrap(), 0x12345678);
+    assert_eq!(reader.position(), 4);
+    assert_eq!(reader.read_u16_le().unwrap(), 0xabcd);
+    assert_eq!(reader.position(), 6);
+    assert_eq!(reader.remaining(), 2);
+
+    let err = reader.read_u32_le().unwrap_err();
+    assert!(err.is_instance_of::<RangeError>());
+    assert_eq!(reader.position(), 6);
+    assert_eq!(reader.read_u8().unwrap(), 0xff);
+}
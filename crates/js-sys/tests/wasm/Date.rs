@@ -143,6 +143,57 @@ fn new() {
     assert!(JsValue::from(Date::new(&JsValue::undefined())).is_object());
 }
 
+#[wasm_bindgen_test]
+fn try_new() {
+    let date = Date::try_new(&"2020-01-02T03:04:05Z".into()).unwrap();
+    assert_eq!(date.get_utc_full_year(), 2020);
+    assert!(Date::try_new(&"not a date".into()).is_none());
+}
+
+#[wasm_bindgen_test]
+fn day_of_year() {
+    assert_eq!(Date::new_with_year_month_day(2023, 0, 1).day_of_year(), 1);
+    assert_eq!(
+        Date::new_with_year_month_day(2023, 11, 31).day_of_year(),
+        365
+    );
+    assert_eq!(
+        Date::new_with_year_month_day(2024, 11, 31).day_of_year(),
+        366
+    );
+    assert_eq!(Date::new_with_year_month_day(2024, 2, 1).day_of_year(), 61);
+    // Year -100 is not a leap year.
+    let date = Date::new(&"-000100-12-31T12:00:00".into());
+    assert_eq!(date.get_full_year() as i32, -100);
+    assert_eq!(date.day_of_year(), 365);
+}
+
+#[wasm_bindgen_test]
+fn iso_week() {
+    // Friday, belongs to the last week of 2020.
+    assert_eq!(
+        Date::new_with_year_month_day(2021, 0, 1).iso_week(),
+        (2020, 53)
+    );
+    // Monday, belongs to the first week of 2025.
+    assert_eq!(
+        Date::new_with_year_month_day(2024, 11, 30).iso_week(),
+        (2025, 1)
+    );
+    assert_eq!(
+        Date::new_with_year_month_day(2020, 11, 31).iso_week(),
+        (2020, 53)
+    );
+    assert_eq!(
+        Date::new_with_year_month_day(2023, 5, 15).iso_week(),
+        (2023, 24)
+    );
+    // Saturday, belongs to the last week of year -1.
+    let date = Date::new_with_year_month_day(2000, 0, 1);
+    date.set_full_year(0);
+    assert_eq!(date.iso_week(), (-1, 52));
+}
+
 #[wasm_bindgen_test]
 fn new_with_year_month() {
     let date1 = Date::new_with_year_month(1975, 7);
@@ -22,6 +22,17 @@ fn new_with_cause() {
     assert_eq!(error.cause(), "some cause");
 }
 
+#[wasm_bindgen_test]
+fn from_display_with_cause() {
+    let rust_error = "12x".parse::<u32>().unwrap_err();
+    let inner = Error::from_display(&rust_error);
+    assert_eq!(inner.message(), rust_error.to_string());
+
+    let outer = Error::with_cause("failed to read config", &inner);
+    assert_eq!(outer.message(), "failed to read config");
+    assert_eq!(outer.cause(), JsValue::from(inner));
+}
+
 #[wasm_bindgen_test]
 fn empty_cause() {
     let error = Error::new("test");
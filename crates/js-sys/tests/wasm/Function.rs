@@ -49,6 +49,44 @@ fn apply() {
     assert_eq!(Array::from_iterable(&arr).unwrap().length(), 1);
 }
 
+#[wasm_bindgen_test]
+fn call_spread() {
+    let args: Array<JsValue> = Array::new();
+    args.push(&1.into());
+    args.push(&3.into());
+    args.push(&2.into());
+    assert_eq!(
+        MAX.with(|max| max.call_spread(&JsValue::undefined(), &args))
+            .unwrap(),
+        JsValue::from(3)
+    );
+
+    let arr = Array::<JsValue>::new();
+    ARRAY_PROTOTYPE2
+        .with(ArrayPrototype::push)
+        .call_spread(&arr, &args)
+        .unwrap();
+    assert_eq!(arr.length(), 3);
+}
+
+#[wasm_bindgen_test]
+fn partial() {
+    let sum = sum_many_arguments();
+    let add_three = sum.partial(&[1.into(), 2.into()]);
+    assert_eq!(
+        add_three
+            .apply(&JsValue::undefined(), &Array::of1(&10.into()))
+            .unwrap(),
+        13
+    );
+    assert_eq!(
+        add_three
+            .apply(&JsValue::undefined(), &Array::new())
+            .unwrap(),
+        3
+    );
+}
+
 #[wasm_bindgen(module = "tests/wasm/Function.js")]
 extern "C" {
     fn get_function_to_bind() -> Function<fn() -> Number>;
@@ -300,6 +338,15 @@ fn length() {
     assert_eq!(ARRAY_PROTOTYPE2.with(ArrayPrototype::push).length(), 1);
 }
 
+#[wasm_bindgen_test]
+fn expect_arity() {
+    assert!(MAX.with(|max| max.expect_arity(2)).is_ok());
+
+    let push = ARRAY_PROTOTYPE2.with(ArrayPrototype::push);
+    let err = push.expect_arity(2).unwrap_err();
+    assert!(err.is_instance_of::<TypeError>());
+}
+
 #[wasm_bindgen_test]
 fn name() {
     assert_eq!(JsValue::from(MAX.with(Function::name)), "max");
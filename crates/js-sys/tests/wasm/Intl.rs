@@ -637,6 +637,14 @@ fn display_names() {
         let name = dn.of("en-US");
         assert!(name.is_some());
         assert!(dn.resolved_options().is_instance_of::<Object>());
+
+        let locales = Array::of(&[JsValue::from("en")]);
+        let opts = Object::new();
+        Reflect::set(&opts, &"type".into(), &"region".into()).unwrap();
+        let dn = Intl::DisplayNames::new(&locales, &opts);
+        let region = dn.of("US");
+        assert!(region.is_some());
+        assert!(region.unwrap().includes("United States", 0));
     }
     #[cfg(js_sys_unstable_apis)]
     {
@@ -17,3 +17,7 @@ exports.get_symbol_iterator_returns_not_object = () => ({
 exports.get_symbol_iterator_returns_object_without_next = () => ({
   [Symbol.iterator]: () => new Object,
 });
+
+exports.get_naturals = function* () {
+  for (let i = 1; ; i++) yield i;
+};
@@ -15,6 +15,8 @@ extern "C" {
     fn get_symbol_iterator_returns_not_object() -> Object;
 
     fn get_symbol_iterator_returns_object_without_next() -> Object;
+
+    fn get_naturals() -> Iterator<Number>;
 }
 
 #[wasm_bindgen_test]
@@ -41,6 +43,22 @@ fn try_iter_handles_iteration_protocol() {
         .is_none());
 }
 
+#[wasm_bindgen_test]
+fn collect_iterable_into_vec() {
+    let set: Set<JsString> = Set::new_typed();
+    set.add(&JsString::from("one"));
+    set.add(&JsString::from("two"));
+    set.add(&JsString::from("three"));
+
+    let values: Vec<JsString> = collect_iterable(&set).unwrap();
+    assert_eq!(values, vec!["one", "two", "three"]);
+
+    assert!(collect_iterable::<JsValue>(&get_not_iterable())
+        .unwrap_err()
+        .is_instance_of::<TypeError>());
+    assert!(collect_iterable::<JsValue>(&get_symbol_iterator_throws()).is_err());
+}
+
 // Typed Iterator tests using Set's typed iterator
 #[wasm_bindgen_test]
 fn typed_iterator_next() {
@@ -90,3 +108,46 @@ fn typed_iterator_collect() {
     assert_eq!(values[1], "b");
     assert_eq!(values[2], "c");
 }
+
+// Iterator helpers are ES2025 and missing on older engines such as Node.js 20.
+fn has_iterator_helpers() -> bool {
+    let Some(iterator) = Reflect::get_str(&global(), &"Iterator".into()).unwrap() else {
+        return false;
+    };
+    let proto: Option<Object> =
+        Reflect::get_str(iterator.unchecked_ref(), &"prototype".into()).unwrap();
+    proto.is_some_and(|proto| Reflect::has_str(&proto, &"map".into()).unwrap_or(false))
+}
+
+#[wasm_bindgen_test]
+fn helpers_chain_lazily() {
+    if !has_iterator_helpers() {
+        return;
+    }
+
+    let square = Closure::new(|n: Number, _: u32| Number::from(n.value_of() * n.value_of()));
+    let even = Closure::new(|n: Number, _: u32| n.value_of() % 2.0 == 0.0);
+
+    // `get_naturals` never finishes, so this only terminates if every step
+    // pulls values on demand.
+    let squares = get_naturals().map(&square).filter(&even).take(3).to_array();
+    let values: Vec<f64> = squares.iter().map(|n| n.value_of()).collect();
+    assert_eq!(values, [4.0, 16.0, 36.0]);
+
+    let rest = get_naturals().drop(2).take(2).to_array();
+    let values: Vec<f64> = rest.iter().map(|n| n.value_of()).collect();
+    assert_eq!(values, [3.0, 4.0]);
+}
+
+#[wasm_bindgen_test]
+fn helpers_reduce() {
+    if !has_iterator_helpers() {
+        return;
+    }
+
+    let sum = get_naturals().take(4).reduce(
+        &mut |acc: Number, n: Number, _| Number::from(acc.value_of() + n.value_of()),
+        &Number::from(0),
+    );
+    assert_eq!(sum, 10);
+}
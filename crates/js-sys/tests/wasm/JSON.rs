@@ -301,3 +301,20 @@ fn stringify_with_replacer_list_typed() {
     .into();
     assert_eq!(output, "{\"a\":1,\"c\":3}");
 }
+
+#[wasm_bindgen_test]
+fn stringify_length() {
+    let obj = Object::new();
+    Reflect::set_str(obj.as_ref(), &"name".into(), &JsValue::from("héllo")).unwrap();
+    Reflect::set_str(
+        obj.as_ref(),
+        &"list".into(),
+        &Array::of3(&1.into(), &2.into(), &3.into()),
+    )
+    .unwrap();
+
+    let json = JSON::stringify(&obj).unwrap();
+    assert_eq!(JSON::stringify_length(&obj).unwrap(), json.length());
+
+    assert!(JSON::stringify_length(&JsValue::UNDEFINED).is_err());
+}
@@ -37,6 +37,29 @@ fn char_at() {
     assert_eq!(JsValue::from(s.char_at(999)), "");
 }
 
+#[wasm_bindgen_test]
+fn is_empty() {
+    assert!(JsString::from("").is_empty());
+    assert!(!JsString::from("a").is_empty());
+}
+
+#[wasm_bindgen_test]
+fn char_at_checked() {
+    let s = JsString::from("abc");
+    assert_eq!(s.char_at_checked(1).unwrap(), "b");
+    assert_eq!(s.char_at_checked(2).unwrap(), "c");
+    assert!(s.char_at_checked(3).is_none());
+    assert!(s.char_at_checked(10).is_none());
+}
+
+#[wasm_bindgen_test]
+fn char_code_at_checked() {
+    let s = JsString::from("A");
+    assert_eq!(s.char_code_at_checked(0), Some(65));
+    assert_eq!(s.char_code_at_checked(1), None);
+    assert_eq!(s.char_code_at_checked(5), None);
+}
+
 #[wasm_bindgen_test]
 fn char_code_at() {
     let s = "Brave new world";
@@ -191,6 +214,22 @@ fn from_code_point() {
     assert!(JsString::from_code_point1(u32::MAX).is_err());
 }
 
+#[wasm_bindgen_test]
+fn variadic_surrogate_pairs() {
+    let s = "a😀b";
+    let units: Vec<u16> = s.encode_utf16().collect();
+    assert_eq!(units.len(), 4);
+    assert_eq!(JsString::from_char_code(&units), s);
+    assert_eq!(JsString::from_char_code(&[]), "");
+
+    let points: Vec<u32> = s.chars().map(u32::from).collect();
+    assert_eq!(JsString::from_code_point(&points).unwrap(), s);
+    assert_eq!(JsString::from_code_point(&points).unwrap().length(), 4);
+
+    let err = JsString::from_code_point(&[0x61, 0x110000]).unwrap_err();
+    assert!(err.is_instance_of::<RangeError>());
+}
+
 #[wasm_bindgen_test]
 fn includes() {
     let str = JsString::from("Blue Whale");
@@ -749,6 +788,20 @@ fn raw() {
     assert!(JsString::raw_0(&JsValue::null().unchecked_into()).is_err());
 }
 
+#[wasm_bindgen_test]
+fn into_rust_string() {
+    for s in ["", "ascii only", "BMP: ½ ☃ 你", "astral: 😀 🥑"] {
+        assert_eq!(String::from(JsString::from(s)), s);
+    }
+
+    let lone_high = JsString::from_char_code(&[0x61, 0xd800, 0x62]);
+    assert_eq!(String::from(&lone_high), "a\u{fffd}b");
+    let lone_low = JsString::from_char_code(&[0xdc00]);
+    assert_eq!(String::from(&lone_low), "\u{fffd}");
+    let reversed_pair = JsString::from_char_code(&[0xde00, 0xd83d]);
+    assert_eq!(String::from(&reversed_pair), "\u{fffd}\u{fffd}");
+}
+
 #[wasm_bindgen_test]
 fn is_valid_utf16() {
     assert!(JsString::from("a").is_valid_utf16());
@@ -771,3 +824,76 @@ fn as_char() {
     assert_eq!(JsString::from_char_code1(0xdc00).as_char(), None);
     assert_eq!(JsString::from_char_code1(0xdfff).as_char(), None);
 }
+
+#[wasm_bindgen_test]
+fn to_utf8_bytes() {
+    assert_eq!(JsString::from("abc").to_utf8_bytes(), b"abc");
+    assert_eq!(JsString::from("🥑").to_utf8_bytes(), "🥑".as_bytes());
+
+    let lone = JsString::from_char_code2(0x61, 0xd800);
+    assert_eq!(lone.to_utf8_bytes(), [b'a', 0xef, 0xbf, 0xbd]);
+}
+
+#[wasm_bindgen_test]
+fn eq_ignore_ascii_case() {
+    assert!(JsString::from("Content-Type").eq_ignore_ascii_case("content-type"));
+    assert!(JsString::from("").eq_ignore_ascii_case(""));
+    assert!(!JsString::from("Content-Type").eq_ignore_ascii_case("content-typ"));
+    assert!(!JsString::from("Content").eq_ignore_ascii_case("content-type"));
+    assert!(!JsString::from("Ä").eq_ignore_ascii_case("ä"));
+    assert!(JsString::from("Ä1").eq_ignore_ascii_case("Ä1"));
+}
+
+#[wasm_bindgen_test]
+fn replace_range() {
+    let js = JsString::from("abcd");
+    assert_eq!(js.replace_range(1, 3, "X"), "aXd");
+    assert_eq!(js.replace_range(0, 0, ">"), ">abcd");
+    assert_eq!(js.replace_range(2, 10, ""), "ab");
+    assert_eq!(js, "abcd");
+}
+
+#[wasm_bindgen_test]
+fn splitn() {
+    let js = JsString::from("a,b,c,d");
+    let parts: Vec<JsString> = js.splitn(2, ",").iter().collect();
+    assert_eq!(parts, ["a", "b"]);
+    assert_eq!(js.splitn(0, ",").length(), 0);
+    assert_eq!(js.splitn(10, ",").length(), 4);
+}
+
+#[wasm_bindgen_test]
+fn split_once() {
+    let js = JsString::from("key=value=x");
+    assert_eq!(
+        js.split_once("="),
+        Some((JsString::from("key"), JsString::from("value=x")))
+    );
+    assert_eq!(
+        js.split_once("=value"),
+        Some((JsString::from("key"), JsString::from("=x")))
+    );
+    assert_eq!(js.split_once(":"), None);
+}
+
+#[wasm_bindgen_test]
+fn graphemes() {
+    let flag = "\u{1F1EB}\u{1F1F7}";
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!(JsString::from(flag).graphemes(), [flag]);
+
+    let text = JsString::from(format!("a{family}e\u{301}"));
+    assert_eq!(text.graphemes(), ["a", family, "e\u{301}"]);
+    assert!(JsString::from("").graphemes().is_empty());
+}
+
+#[wasm_bindgen_test]
+fn lines() {
+    let s = JsString::from("one\ntwo\r\n\nfour\n");
+    assert_eq!(s.lines().count(), 4);
+    let lines: Vec<String> = s.lines().map(String::from).collect();
+    assert_eq!(lines, ["one", "two", "", "four"]);
+
+    assert_eq!(JsString::from("").lines().count(), 0);
+    assert_eq!(JsString::from("no newline").lines().count(), 1);
+}
@@ -186,6 +186,27 @@ fn typed_entries_iterator() {
     assert_eq!(count, 2);
 }
 
+#[wasm_bindgen_test]
+fn iter() {
+    let map: Map<JsString, Number> = Map::new_typed();
+    map.set(&JsString::from("b"), &Number::from(2));
+    map.set(&JsString::from("a"), &Number::from(1));
+    map.set(&JsString::from("c"), &Number::from(3));
+
+    let pairs: Vec<(JsString, Number)> = map.iter().collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (JsString::from("b"), Number::from(2)),
+            (JsString::from("a"), Number::from(1)),
+            (JsString::from("c"), Number::from(3)),
+        ]
+    );
+
+    let pairs: Result<Vec<_>, _> = map.try_iter().collect();
+    assert_eq!(pairs.unwrap().len(), 3);
+}
+
 #[wasm_bindgen_test]
 fn new_from_entries() {
     let entries: Array<ArrayTuple<(JsString, JsString)>> = Array::new_typed();
@@ -249,3 +270,121 @@ fn get_option() {
     // Non-existent key - should return None
     assert_eq!(map.get_checked(&JsString::from("missing")), None);
 }
+
+#[wasm_bindgen_test]
+fn from_iter() {
+    let mut map: Map<Number, JsString> = [(1, "a"), (2, "b")]
+        .into_iter()
+        .map(|(k, v)| (Number::from(k), JsString::from(v)))
+        .collect();
+    assert_eq!(map.size(), 2);
+    assert_eq!(map.get_checked(&Number::from(2)), Some(JsString::from("b")));
+
+    map.extend([(Number::from(1), JsString::from("c"))]);
+    assert_eq!(map.size(), 2);
+    assert_eq!(map.get_checked(&Number::from(1)), Some(JsString::from("c")));
+}
+
+#[wasm_bindgen_test]
+fn get_or_insert_with() {
+    let map: Map<JsString, Number> = Map::new_typed();
+    let key = JsString::from("a");
+    let mut calls = 0;
+    let mut default = || {
+        calls += 1;
+        Number::from(1)
+    };
+    assert_eq!(map.get_or_insert_with(&key, &mut default), 1);
+    assert_eq!(map.get_or_insert_with(&key, &mut default), 1);
+    assert_eq!(calls, 1);
+    assert_eq!(map.size(), 1);
+
+    let map: Map = Map::new_typed();
+    map.set(&"u".into(), &JsValue::UNDEFINED);
+    let value = map.get_or_insert_with(&"u".into(), &mut || JsValue::from(1));
+    assert!(value.is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn json_round_trip() {
+    let map: Map<JsString, JsValue> = Map::new_typed();
+    map.set(&"a".into(), &1.into());
+    map.set(&"b".into(), &"two".into());
+    let json = map.to_json_string().unwrap();
+    assert_eq!(json, r#"{"a":1,"b":"two"}"#);
+
+    let parsed = Map::from_json_str(&json).unwrap();
+    assert_eq!(parsed.size(), 2);
+    assert_eq!(parsed.get_checked(&"a".into()), Some(1.into()));
+    assert_eq!(parsed.get_checked(&"b".into()), Some("two".into()));
+
+    assert!(Map::from_json_str("[1, 2")
+        .unwrap_err()
+        .is_instance_of::<SyntaxError>());
+    assert!(Map::from_json_str("3")
+        .unwrap_err()
+        .is_instance_of::<TypeError>());
+    assert!(Map::from_json_str("[1, 2]")
+        .unwrap_err()
+        .is_instance_of::<TypeError>());
+}
+
+fn parity(n: Number) -> JsString {
+    if n.value_of() % 2.0 == 0.0 {
+        "even".into()
+    } else {
+        "odd".into()
+    }
+}
+
+#[wasm_bindgen_test]
+fn group_by() {
+    // Check if Map.groupBy exists (ES2024, not available in all engines)
+    let map_constructor = Map::<JsValue, JsValue>::new().constructor();
+    if !Reflect::has_str(map_constructor.as_ref(), &"groupBy".into()).unwrap() {
+        return;
+    }
+
+    let items: Array<Number> = Array::of(&[5, 2, 3, 4, 1].map(Number::from));
+    let groups = Map::group_by(&items, &mut |n: Number, _| parity(n));
+    assert_eq!(groups.size(), 2);
+    let keys: Vec<String> = groups.iter().map(|(key, _)| key.into()).collect();
+    assert_eq!(keys, ["odd", "even"]);
+
+    let odd: Vec<f64> = groups
+        .get_checked(&"odd".into())
+        .unwrap()
+        .iter()
+        .map(|n| n.value_of())
+        .collect();
+    assert_eq!(odd, [5.0, 3.0, 1.0]);
+    let even: Vec<f64> = groups
+        .get_checked(&"even".into())
+        .unwrap()
+        .iter()
+        .map(|n| n.value_of())
+        .collect();
+    assert_eq!(even, [2.0, 4.0]);
+}
+
+#[wasm_bindgen_test]
+fn try_group_by() {
+    // Check if Map.groupBy exists (ES2024, not available in all engines)
+    let map_constructor = Map::<JsValue, JsValue>::new().constructor();
+    if !Reflect::has_str(map_constructor.as_ref(), &"groupBy".into()).unwrap() {
+        return;
+    }
+
+    let items: Array<Number> = Array::of(&[1, 2, 3].map(Number::from));
+    let groups = Map::try_group_by(&items, &mut |n: Number, _| Ok(parity(n))).unwrap();
+    assert_eq!(groups.size(), 2);
+
+    let result = Map::<JsString, Array<Number>>::try_group_by(&items, &mut |_, index| {
+        if index == 1 {
+            Err(wasm_bindgen::JsError::new("group error"))
+        } else {
+            Ok(JsString::from("all"))
+        }
+    });
+    assert!(result.is_err());
+}
@@ -275,3 +275,59 @@ fn pi() {
         assert_eq!(*pi, std::f64::consts::PI);
     });
 }
+
+#[wasm_bindgen_test]
+fn lerp() {
+    assert_eq!(Math::lerp(0.0, 10.0, 0.5), 5.0);
+    assert_eq!(Math::lerp(2.0, 4.0, 0.0), 2.0);
+    assert_eq!(Math::lerp(2.0, 4.0, 1.0), 4.0);
+
+    let t = Math::inverse_lerp(-3.0, 7.0, 4.5);
+    assert_eq!(t, 0.75);
+    assert_eq!(Math::lerp(-3.0, 7.0, t), 4.5);
+    assert!(Math::inverse_lerp(1.0, 1.0, 1.0).is_nan());
+}
+
+#[wasm_bindgen_test]
+fn factorial() {
+    assert_eq!(Math::factorial(0), 1.0);
+    assert_eq!(Math::factorial(1), 1.0);
+    assert_eq!(Math::factorial(5), 120.0);
+    assert_eq!(Math::factorial(18), 6402373705728000.0);
+    assert!(Math::factorial(171).is_infinite());
+}
+
+#[wasm_bindgen_test]
+fn round_ties_even() {
+    assert_eq!(Math::round_ties_even(0.5), 0.0);
+    assert_eq!(Math::round_ties_even(1.5), 2.0);
+    assert_eq!(Math::round_ties_even(2.5), 2.0);
+    assert_eq!(Math::round_ties_even(-2.5), -2.0);
+    assert_eq!(Math::round_ties_even(-3.5), -4.0);
+    assert_eq!(Math::round_ties_even(2.4), 2.0);
+    assert_eq!(Math::round_ties_even(2.6), 3.0);
+    assert_eq!(Math::round_ties_even(-2.6), -3.0);
+    assert!(Math::round_ties_even(-0.5).is_sign_negative());
+    assert_eq!(
+        Math::round_ties_even(9007199254740991.0),
+        9007199254740991.0
+    );
+    assert!(Math::round_ties_even(f64::NAN).is_nan());
+    assert_eq!(Math::round_ties_even(f64::NEG_INFINITY), f64::NEG_INFINITY);
+}
+
+#[wasm_bindgen_test]
+fn ln_gamma() {
+    let close = |a: f64, b: f64| (a - b).abs() <= 1e-12 * b.abs().max(1.0);
+    // ln Γ(1) = ln Γ(2) = 0
+    assert!(close(Math::ln_gamma(1.0), 0.0));
+    assert!(close(Math::ln_gamma(2.0), 0.0));
+    // ln Γ(1/2) = ln √π
+    assert!(close(Math::ln_gamma(0.5), 0.5723649429247));
+    // ln Γ(10) = ln 9!
+    assert!(close(Math::ln_gamma(10.0), 12.801827480081469));
+    assert!(close(Math::ln_gamma(-0.5), 1.265512123484645));
+    assert!(close(Math::ln_gamma(100.5), 361.4355404677776));
+    assert!(Math::ln_gamma(0.0).is_infinite());
+    assert!(Math::ln_gamma(-2.0).is_infinite());
+}
@@ -212,3 +212,95 @@ fn consts() {
         "POSITIVE_INFINITY"
     );
 }
+
+#[wasm_bindgen_test]
+fn is_between() {
+    assert!(Number::from(5).is_between(&1.into(), &10.into()));
+    assert!(Number::from(1).is_between(&1.into(), &10.into()));
+    assert!(Number::from(10).is_between(&1.into(), &10.into()));
+    assert!(!Number::from(11).is_between(&1.into(), &10.into()));
+    assert!(!Number::from(5).is_between(&10.into(), &1.into()));
+    assert!(!Number::from(f64::NAN).is_between(&1.into(), &10.into()));
+    assert!(!Number::from(5).is_between(&f64::NAN.into(), &10.into()));
+}
+
+#[wasm_bindgen_test]
+fn to_grouped_string() {
+    let n = Number::from(1234567);
+    assert_eq!(n.to_grouped_string("en-US").unwrap(), "1,234,567");
+    assert!(n.to_grouped_string("not a locale").is_err());
+}
+
+#[wasm_bindgen_test]
+fn u32_bit_helpers() {
+    assert_eq!(Number::from(1).leading_zeros_u32(), 31);
+    assert_eq!(Number::from(0).leading_zeros_u32(), 32);
+    assert_eq!(Number::from(-1).leading_zeros_u32(), 0);
+
+    assert!(Number::from(1).is_power_of_two_u32());
+    assert!(Number::from(64).is_power_of_two_u32());
+    assert!(!Number::from(0).is_power_of_two_u32());
+    assert!(!Number::from(6).is_power_of_two_u32());
+
+    assert_eq!(Number::from(5).next_power_of_two_u32(), 8.0);
+    assert_eq!(Number::from(8).next_power_of_two_u32(), 8.0);
+    assert_eq!(Number::from(0).next_power_of_two_u32(), 1.0);
+    assert_eq!(
+        Number::from(0x8000_0001u32).next_power_of_two_u32(),
+        4294967296.0
+    );
+}
+
+#[wasm_bindgen_test]
+fn dyn_into() {
+    let n: Number = JsValue::from(1.5).dyn_into().unwrap();
+    assert_eq!(n, 1.5);
+    let err = JsValue::from("1.5").dyn_into::<Number>().unwrap_err();
+    assert_eq!(err, "1.5");
+}
+
+#[wasm_bindgen_test]
+fn ordered_number() {
+    let mut map = std::collections::HashMap::new();
+    map.insert(OrderedNumber::new(Number::from(1.5)), "one and a half");
+    map.insert(OrderedNumber::new(Number::from(f64::NAN)), "nan");
+    // A NaN with a different payload canonicalizes to the same key.
+    let other_nan = f64::from_bits(f64::NAN.to_bits() | 1);
+    map.insert(OrderedNumber::new(Number::from(other_nan)), "other nan");
+    assert_eq!(map.len(), 2);
+    assert_eq!(
+        map[&OrderedNumber::new(Number::from(f64::NAN))],
+        "other nan"
+    );
+    assert_eq!(
+        map[&OrderedNumber::new(Number::from(1.5))],
+        "one and a half"
+    );
+
+    assert_ne!(
+        OrderedNumber::new(Number::from(0.0)),
+        OrderedNumber::new(Number::from(-0.0))
+    );
+
+    let mut sorted = [f64::NAN, 2.0, f64::NEG_INFINITY, -0.0, 0.0]
+        .into_iter()
+        .map(|n| OrderedNumber::new(Number::from(n)))
+        .collect::<Vec<_>>();
+    sorted.sort();
+    let sorted = sorted
+        .into_iter()
+        .map(|n| f64::from(n.into_inner()))
+        .collect::<Vec<_>>();
+    assert_eq!(sorted[..4], [f64::NEG_INFINITY, -0.0, 0.0, 2.0]);
+    assert!(sorted[1].is_sign_negative());
+    assert!(sorted[4].is_nan());
+}
+
+#[wasm_bindgen_test]
+fn signum() {
+    assert_eq!(Number::from(-5).signum(), -1);
+    assert_eq!(Number::from(5).signum(), 1);
+    assert_eq!(Number::from(0).signum(), 0);
+    assert!(Number::from(-0.0).signum().value_of().is_sign_negative());
+    assert!(Number::from(f64::NAN).signum().value_of().is_nan());
+}
@@ -9,3 +9,12 @@ exports.symbol_key = function() {
 
 exports.Foo = class {};
 exports.Bar = class {};
+
+exports.Greeter = class {
+  constructor(name) { this.name = name; }
+  greet() { return `hello ${this.name}`; }
+};
+
+exports.with_getter = function() {
+  return { get answer() { return 42; } };
+};
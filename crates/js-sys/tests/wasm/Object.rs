@@ -27,6 +27,7 @@ extern "C" {
 #[wasm_bindgen(module = "tests/wasm/Object.js")]
 extern "C" {
     fn map_with_symbol_key() -> Object;
+    fn with_getter() -> Object;
 
     #[cfg(not(js_sys_unstable_apis))]
     fn symbol_key() -> JsValue;
@@ -42,6 +43,13 @@ extern "C" {
     static FOO_PROTOTYPE: Object;
     #[wasm_bindgen(thread_local_v2, js_name = prototype, js_namespace = Bar)]
     static BAR_PROTOTYPE: Object;
+
+    #[wasm_bindgen(extends = Object)]
+    type Greeter;
+    #[wasm_bindgen(constructor)]
+    fn new(name: &str) -> Greeter;
+    #[wasm_bindgen(method)]
+    fn greet(this: &Greeter) -> String;
 }
 
 #[cfg(not(js_sys_unstable_apis))]
@@ -267,6 +275,28 @@ fn get_own_property_descriptor() {
     }
 }
 
+#[wasm_bindgen_test]
+fn get_own_property_descriptor_checked() {
+    let obj = with_getter();
+    let desc = Object::get_own_property_descriptor_checked(&obj, &"answer".into()).unwrap();
+    assert!(desc.get_get().unwrap().is_function());
+    assert!(desc.get_set().is_none());
+    assert!(desc.get_value().is_none());
+    assert!(desc.get_writable().is_none());
+    assert_eq!(desc.get_enumerable(), Some(true));
+    assert_eq!(desc.get_configurable(), Some(true));
+
+    let desc = Object::get_own_property_descriptor_checked(&foo_42(), &"foo".into()).unwrap();
+    assert_eq!(desc.get_value().unwrap(), 42);
+    assert!(desc.get_get().is_none());
+
+    let desc =
+        Object::get_own_property_descriptor_checked(&map_with_symbol_key(), symbol_key().as_ref());
+    assert_eq!(desc.unwrap().get_value().unwrap(), 42);
+
+    assert!(Object::get_own_property_descriptor_checked(&obj, &"missing".into()).is_none());
+}
+
 #[wasm_bindgen_test]
 fn get_own_property_descriptors() {
     let foo = foo_42();
@@ -521,3 +551,239 @@ fn from_entries_typed() {
         "qux"
     );
 }
+
+#[wasm_bindgen_test]
+fn merge_deep() {
+    let base: Object = JSON::parse(r#"{"a":{"x":1},"b":[1,2],"c":"base"}"#)
+        .unwrap()
+        .unchecked_into();
+    let overlay: Object = JSON::parse(r#"{"a":{"y":2},"b":[3],"d":true}"#)
+        .unwrap()
+        .unchecked_into();
+
+    let merged = Object::merge_deep(&base, &overlay).unwrap();
+    assert_eq!(
+        JSON::stringify(&merged).unwrap(),
+        r#"{"a":{"x":1,"y":2},"b":[3],"c":"base","d":true}"#
+    );
+
+    // Inputs are left untouched.
+    assert_eq!(
+        JSON::stringify(&base).unwrap(),
+        r#"{"a":{"x":1},"b":[1,2],"c":"base"}"#
+    );
+
+    // Nested objects from either side are copied, not shared.
+    let nested_base: Object = JSON::parse(r#"{"a":{"x":1},"e":{"n":{"m":1}}}"#)
+        .unwrap()
+        .unchecked_into();
+    let nested_overlay: Object = JSON::parse(r#"{"f":{"z":1}}"#).unwrap().unchecked_into();
+    let nested = Object::merge_deep(&nested_base, &nested_overlay).unwrap();
+    let path = |obj: &Object, path: &str| -> Object {
+        Object::path_get(obj, path).unwrap().unchecked_into()
+    };
+    Reflect::set_str(&path(&nested, "e.n"), &"m".into(), &2.into()).unwrap();
+    Reflect::set_str(&path(&nested, "f"), &"z".into(), &2.into()).unwrap();
+    assert_eq!(
+        JSON::stringify(&nested_base).unwrap(),
+        r#"{"a":{"x":1},"e":{"n":{"m":1}}}"#
+    );
+    assert_eq!(
+        JSON::stringify(&nested_overlay).unwrap(),
+        r#"{"f":{"z":1}}"#
+    );
+    assert_eq!(
+        JSON::stringify(&nested).unwrap(),
+        r#"{"a":{"x":1},"e":{"n":{"m":2}},"f":{"z":2}}"#
+    );
+
+    // Overlay wins when only one side is a plain object.
+    let overlay: Object = JSON::parse(r#"{"a":5}"#).unwrap().unchecked_into();
+    let merged = Object::merge_deep(&base, &overlay).unwrap();
+    assert_eq!(
+        JSON::stringify(&merged).unwrap(),
+        r#"{"a":5,"b":[1,2],"c":"base"}"#
+    );
+
+    // Cyclic inputs hit the depth bound instead of recursing forever.
+    let cyclic = Object::new();
+    Reflect::set(&cyclic, &"self".into(), &cyclic).unwrap();
+    assert!(Object::merge_deep(&cyclic, &cyclic)
+        .unwrap_err()
+        .is_instance_of::<RangeError>());
+}
+
+#[wasm_bindgen_test]
+fn ordered_entries() {
+    let obj = Object::new();
+    for key in ["2", "1", "b", "a"] {
+        Reflect::set(&obj, &key.into(), &JsValue::from(key)).unwrap();
+    }
+
+    let keys: Vec<String> = Object::ordered_entries(&obj)
+        .iter()
+        .map(|entry| String::from(entry.into_tuple().0))
+        .collect();
+    assert_eq!(keys, ["1", "2", "b", "a"]);
+}
+
+#[wasm_bindgen_test]
+fn to_map() {
+    let obj: Object = JSON::parse(r#"{"a":1,"b":2}"#).unwrap().unchecked_into();
+    let map: Map<JsString, Number> = Object::to_map(&obj).unwrap();
+    assert_eq!(map.size(), 2);
+
+    let entries: Vec<(String, f64)> = map
+        .iter()
+        .map(|(k, v)| (String::from(k), v.value_of()))
+        .collect();
+    assert_eq!(entries, [("a".to_string(), 1.0), ("b".to_string(), 2.0)]);
+}
+
+#[wasm_bindgen_test]
+fn diff() {
+    let old: Object = JSON::parse(r#"{"a":1,"b":2,"d":5}"#)
+        .unwrap()
+        .unchecked_into();
+    let new: Object = JSON::parse(r#"{"a":1,"b":3,"c":4}"#)
+        .unwrap()
+        .unchecked_into();
+    let changes = Object::diff(&old, &new).unwrap();
+    assert!(!Reflect::has_str(&changes, &"a".into()).unwrap());
+    assert_eq!(
+        Reflect::get_str(&changes, &"b".into()).unwrap(),
+        Some(3.into())
+    );
+    assert_eq!(
+        Reflect::get_str(&changes, &"c".into()).unwrap(),
+        Some(4.into())
+    );
+    assert_eq!(
+        Reflect::get_str(&changes, &"d".into()).unwrap(),
+        Some(Object::diff_removed().into())
+    );
+
+    // A key set to `undefined` is a change, not a removal.
+    let undefined: Object = JSON::parse(r#"{"a":1,"b":2}"#).unwrap().unchecked_into();
+    Reflect::set_str(&undefined, &"d".into(), &JsValue::UNDEFINED).unwrap();
+    let changes = Object::diff(&old, &undefined).unwrap();
+    assert!(Reflect::has_str(&changes, &"d".into()).unwrap());
+    assert_eq!(Reflect::get_str(&changes, &"d".into()).unwrap(), None);
+
+    assert_eq!(Object::keys(&Object::diff(&new, &new).unwrap()).length(), 0);
+
+    // `"__proto__"` is recorded like any other key.
+    let old: Object = JSON::parse(r#"{"__proto__":1,"x":2}"#)
+        .unwrap()
+        .unchecked_into();
+    let new: Object = JSON::parse(r#"{"__proto__":3}"#).unwrap().unchecked_into();
+    let changes = Object::diff(&old, &new).unwrap();
+    assert!(Object::get_prototype_of(&changes).is_null());
+    assert!(Object::has_own_str(&changes, &"__proto__".into()).unwrap());
+    assert_eq!(
+        Reflect::get_str(&changes, &"__proto__".into()).unwrap(),
+        Some(3.into())
+    );
+    let changes = Object::diff(&old, &JSON::parse("{}").unwrap().unchecked_into()).unwrap();
+    assert_eq!(
+        Reflect::get_str(&changes, &"__proto__".into()).unwrap(),
+        Some(Object::diff_removed().into())
+    );
+}
+
+#[wasm_bindgen_test]
+fn path_get() {
+    let obj = JSON::parse(r#"{"a":{"b":{"c":42},"n":1}}"#).unwrap();
+    assert_eq!(Object::path_get(&obj, "a.b.c"), Some(42.into()));
+    assert!(Object::path_get(&obj, "a.b").unwrap().is_object());
+    assert_eq!(Object::path_get(&obj, "a.x"), None);
+    assert_eq!(Object::path_get(&obj, "a.n.c"), None);
+    assert_eq!(Object::path_get(&JsValue::NULL, "a"), None);
+}
+
+#[wasm_bindgen_test]
+fn to_string_map() {
+    let obj: Object = JSON::parse(r#"{"b":2,"a":1}"#).unwrap().unchecked_into();
+    let map = Object::to_string_map(&obj).unwrap();
+    let keys: Vec<&str> = map.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["a", "b"]);
+    assert_eq!(map["a"], 1);
+    assert_eq!(map["b"], 2);
+}
+
+#[wasm_bindgen_test]
+fn enumerable_own_keys() {
+    let obj = Object::new();
+    Reflect::set_str(&obj, &"visible".into(), &1.into()).unwrap();
+
+    let descriptor = js_sys::PropertyDescriptor::new_value(&JsValue::from(2));
+    descriptor.set_enumerable(false);
+    Object::define_property_str(&obj, &"hidden".into(), &descriptor).unwrap();
+
+    let keys: Vec<String> = Object::enumerable_own_keys(&obj)
+        .unwrap()
+        .iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(keys, ["visible"]);
+}
+
+#[wasm_bindgen_test]
+fn shallow_clone() {
+    let greeter = Greeter::new("world");
+    let clone: Greeter = Object::shallow_clone(&greeter).unchecked_into();
+    assert_eq!(clone.greet(), "hello world");
+    assert!(Object::has_own_str(&clone, &"name".into()).unwrap());
+
+    Reflect::set_str(&clone, &"name".into(), &"clone".into()).unwrap();
+    assert_eq!(clone.greet(), "hello clone");
+    assert_eq!(greeter.greet(), "hello world");
+}
+
+#[wasm_bindgen_test]
+fn group_by() {
+    // Check if Object.groupBy exists (ES2024, not available in all engines)
+    let object_constructor = Object::new().constructor();
+    if !Reflect::has_str(object_constructor.as_ref(), &"groupBy".into()).unwrap() {
+        return;
+    }
+
+    let items: Array<Number> = Array::of(&[5, 2, 3, 4, 1].map(Number::from));
+    let parity = |n: &Number| {
+        JsValue::from(if n.value_of() % 2.0 == 0.0 {
+            "even"
+        } else {
+            "odd"
+        })
+    };
+    let groups = Object::group_by(&items, &mut |n, _| parity(&n));
+    assert!(Object::get_prototype_of(&groups).is_null());
+
+    let bucket = |groups: &Object<Array<Number>>, key: &str| -> Vec<f64> {
+        let bucket = Reflect::get_str(groups, &key.into()).unwrap().unwrap();
+        bucket.iter().map(|n| n.value_of()).collect()
+    };
+    assert_eq!(bucket(&groups, "odd"), [5.0, 3.0, 1.0]);
+    assert_eq!(bucket(&groups, "even"), [2.0, 4.0]);
+
+    let groups = Object::try_group_by(&items, &mut |n, _| Ok(parity(&n))).unwrap();
+    assert_eq!(bucket(&groups, "even"), [2.0, 4.0]);
+
+    let result = Object::try_group_by(&items, &mut |_, _| {
+        Err::<JsValue, _>(JsError::new("group error"))
+    });
+    assert!(result.is_err());
+}
+
+#[wasm_bindgen_test]
+fn spread_into() {
+    let extra: Object = JSON::parse(r#"{"name":"spread","extra":1}"#)
+        .unwrap()
+        .unchecked_into();
+    let greeter: Greeter = Object::spread_into(Greeter::new("world"), &extra);
+    assert_eq!(greeter.greet(), "hello spread");
+    assert_eq!(
+        Reflect::get_str(&greeter, &"extra".into()).unwrap(),
+        Some(1.into())
+    );
+}
@@ -75,6 +75,17 @@ async fn test_promise_resolve_infers_type() {
     assert_eq!(result.value(), "hello");
 }
 
+#[wasm_bindgen_test]
+async fn test_promise_resolve_and_reject_typed() {
+    let resolved: Promise<Number> = Promise::resolve(&Number::from(42));
+    let value: Number = JsFuture::from(resolved).await.unwrap();
+    assert_eq!(value, 42);
+
+    let rejected: Promise<Number> = Promise::reject_typed(&JsValue::from("nope"));
+    let reason = JsFuture::from(rejected).await.unwrap_err();
+    assert_eq!(reason, "nope");
+}
+
 #[wasm_bindgen_test]
 async fn test_promise_map_with_closure() {
     let test_val = TestValue::new(&JsString::from("start"));
@@ -307,6 +318,24 @@ async fn test_promise_finally() {
     assert_eq!(result.value(), "finally_test");
 }
 
+#[wasm_bindgen_test]
+async fn test_promise_finally_typed() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let promise: Promise<Number> = Promise::resolve(&Number::from(42));
+
+    let ran = Rc::new(Cell::new(false));
+    let ran2 = ran.clone();
+    let closure = Closure::new(move || ran2.set(true));
+
+    let result_promise: Promise<Number> = promise.finally_typed(&closure);
+
+    let result: Number = JsFuture::from(result_promise).await.unwrap();
+    assert!(ran.get());
+    assert_eq!(result, 42);
+}
+
 #[wasm_bindgen_test]
 async fn test_promise_then2() {
     let test_val = TestValue::new(&JsString::from("then2_test"));
@@ -424,6 +453,32 @@ fn race_iterable() {
     let result = Promise::race_iterable(&arr);
     assert!(JsValue::from(result).is_object());
 }
+#[wasm_bindgen_test]
+async fn typed_combinators_preserve_element_type() {
+    let arr: Array<Promise<Number>> = Array::new_typed();
+    for n in [1, 2, 3] {
+        arr.push(&Promise::resolve(&Number::from(n)));
+    }
+
+    let all: Array<Number> = JsFuture::from(Promise::all_iterable(&arr)).await.unwrap();
+    let values: Vec<f64> = all.iter().map(|n| n.value_of()).collect();
+    assert_eq!(values, [1.0, 2.0, 3.0]);
+
+    let first: Number = JsFuture::from(Promise::race_iterable(&arr)).await.unwrap();
+    assert_eq!(first, 1);
+
+    let first_ok: Number = JsFuture::from(Promise::any_iterable(&arr)).await.unwrap();
+    assert_eq!(first_ok, 1);
+
+    let rejected: Array<Promise<Number>> = Array::new_typed();
+    rejected.push(&Promise::reject_typed(&JsValue::from("a")));
+    rejected.push(&Promise::reject_typed(&JsValue::from("b")));
+    let err = JsFuture::from(Promise::any_iterable(&rejected))
+        .await
+        .unwrap_err();
+    assert!(err.is_instance_of::<AggregateError>());
+}
+
 #[wasm_bindgen]
 pub fn rust_create_number_promise(value: f64) -> Promise<Number> {
     Promise::resolve(&Number::from(value))
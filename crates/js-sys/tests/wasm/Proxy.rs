@@ -43,3 +43,35 @@ fn revocable() {
     assert!(obj.b().is_err());
     assert!(JsValue::from(obj).is_object());
 }
+
+#[wasm_bindgen_test]
+fn from_handler() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Logger(Rc<RefCell<Vec<JsValue>>>);
+
+    impl ProxyHandler for Logger {
+        fn get(
+            &self,
+            target: &JsValue,
+            key: &JsValue,
+            _receiver: &JsValue,
+        ) -> Result<JsValue, JsValue> {
+            self.0.borrow_mut().push(key.clone());
+            let value = Reflect::get_str(target.unchecked_ref::<Object>(), key.unchecked_ref())?;
+            Ok(value.unwrap_or_default())
+        }
+    }
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let proxy = Proxy::from_handler(&proxy_target(), Logger(log.clone()));
+    let obj = Custom::from(JsValue::from(proxy.clone()));
+    assert_eq!(obj.a().unwrap(), 100);
+    assert_eq!(*log.borrow(), vec![JsValue::from("a")]);
+
+    // Traps that are not overridden forward to the target.
+    let proxy = proxy.unchecked_into::<Object>();
+    assert!(Reflect::has_str(&proxy, &"a".into()).unwrap());
+    assert!(!Reflect::has_str(&proxy, &"b".into()).unwrap());
+}
@@ -39,3 +39,12 @@ exports.throw_all_the_time = () => new Proxy({}, {
   apply() { throw new Error("nope"); },
   construct() { throw new Error("nope"); },
 });
+
+exports.mixed_keys = () => ({
+  b: 1,
+  [Symbol.for("first")]: 1,
+  2: 1,
+  a: 1,
+  1: 1,
+  [Symbol.for("second")]: 1,
+});
@@ -27,6 +27,7 @@ extern "C" {
     fn set_x(this: &Rectangle, x: u32);
 
     fn throw_all_the_time() -> Object;
+    fn mixed_keys() -> Object;
 }
 
 #[wasm_bindgen]
@@ -85,6 +86,22 @@ fn construct_with_new_target() {
     assert_eq!(Rectangle::from(instance).x(), 10);
 }
 
+#[wasm_bindgen_test]
+fn construct_fixed_arity() {
+    let instance = Reflect::construct2(&RECTANGLE_CLASS, &10.into(), &20.into()).unwrap();
+    let rectangle = Rectangle::from(JsValue::from(instance));
+    assert_eq!(rectangle.x(), 10);
+    assert!(rectangle.is_instance_of::<Object>());
+
+    let instance = Reflect::construct0(&RECTANGLE_CLASS).unwrap();
+    assert!(Rectangle::from(JsValue::from(instance))
+        .x_jsval()
+        .is_undefined());
+
+    let not_a_constructor = get_char_at();
+    assert!(Reflect::construct1(&not_a_constructor, &1.into()).is_err());
+}
+
 #[wasm_bindgen_test]
 fn define_property() {
     let value = DefinePropertyAttrs::from(JsValue::from(Object::new()));
@@ -193,6 +210,22 @@ fn own_keys() {
     });
 }
 
+#[wasm_bindgen_test]
+fn own_keys_vec() {
+    let obj = mixed_keys();
+    let keys = Reflect::own_keys_vec(&obj).unwrap();
+    assert_eq!(keys.len(), 6);
+    assert_eq!(keys[..4], ["1", "2", "b", "a"].map(JsValue::from));
+    assert_eq!(keys[4], JsValue::from(Symbol::for_("first")));
+    assert_eq!(keys[5], JsValue::from(Symbol::for_("second")));
+
+    let string_keys = Reflect::string_keys(&obj).unwrap();
+    assert_eq!(string_keys, ["1", "2", "b", "a"].map(JsString::from));
+
+    assert!(Reflect::own_keys_vec(&throw_all_the_time()).is_err());
+    assert!(Reflect::string_keys(&throw_all_the_time()).is_err());
+}
+
 #[wasm_bindgen_test]
 fn prevent_extensions() {
     let obj = Object::new();
@@ -255,6 +288,29 @@ fn set_with_receiver() {
     );
 }
 
+#[wasm_bindgen_test]
+fn set_many() {
+    let obj = Object::new();
+    Reflect::set_many(
+        &obj,
+        &[
+            ("a", &JsValue::from(1)),
+            ("b", &JsValue::from("two")),
+            ("c", &JsValue::TRUE),
+        ],
+    )
+    .unwrap();
+    assert_eq!(Reflect::get_str(&obj, &"a".into()).unwrap().unwrap(), 1);
+    assert_eq!(Reflect::get_str(&obj, &"b".into()).unwrap().unwrap(), "two");
+    assert_eq!(
+        Reflect::get_str(&obj, &"c".into()).unwrap().unwrap(),
+        JsValue::TRUE
+    );
+
+    let frozen = Object::freeze(&Object::new());
+    assert!(Reflect::set_many(&frozen, &[("a", &JsValue::from(1))]).is_err());
+}
+
 #[wasm_bindgen_test]
 fn set_prototype_of() {
     let obj = Object::new();
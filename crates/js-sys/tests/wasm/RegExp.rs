@@ -10,6 +10,19 @@ fn regexp_inheritance() {
     let _: &Object = re.as_ref();
 }
 
+#[wasm_bindgen_test]
+fn escape() {
+    let escaped = RegExp::escape("a.b*c");
+    let re = RegExp::new(&String::from(&escaped), "");
+    assert!(re.test("a.b*c"));
+    assert!(!re.test("axbbc"));
+
+    let escaped = RegExp::escape("(1 + 2) = 3?");
+    let re = RegExp::new(&format!("^{}$", escaped), "");
+    assert!(re.test("(1 + 2) = 3?"));
+    assert!(!re.test("1 + 2 = 3"));
+}
+
 #[wasm_bindgen_test]
 fn exec() {
     #[cfg(not(js_sys_unstable_apis))]
@@ -178,3 +191,45 @@ fn unicode() {
     let re = RegExp::new("\u{61}", "u");
     assert!(re.unicode());
 }
+
+#[wasm_bindgen_test]
+fn replace_each() {
+    let re = RegExp::new(r"(\d)(\d)", "g");
+    let swapped = re.replace_each("12 34 5", &mut |m| {
+        let a = m.get(1).as_string().unwrap();
+        let b = m.get(2).as_string().unwrap();
+        JsString::from(b + &a)
+    });
+    assert_eq!(swapped, "21 43 5");
+    assert_eq!(re.last_index(), 0);
+
+    let re = RegExp::new(r"(\d)(\d)", "");
+    let swapped = re.replace_each("12 34", &mut |m| {
+        assert_eq!(Reflect::get(&m, &"index".into()).unwrap(), 0);
+        let a = m.get(1).as_string().unwrap();
+        let b = m.get(2).as_string().unwrap();
+        JsString::from(b + &a)
+    });
+    assert_eq!(swapped, "21 34");
+
+    let re = RegExp::new("x*", "g");
+    let replaced = re.replace_each("ab", &mut |_| JsString::from("-"));
+    assert_eq!(replaced, "-a-b-");
+}
+
+#[wasm_bindgen_test]
+fn flags_struct() {
+    let flags = RegExp::new("a", "gi").flags_struct();
+    assert_eq!(
+        flags,
+        RegExpFlags {
+            global: true,
+            ignore_case: true,
+            ..RegExpFlags::default()
+        }
+    );
+
+    let flags = RegExp::new("a", "dmsy").flags_struct();
+    assert!(flags.has_indices && flags.multiline && flags.dot_all && flags.sticky);
+    assert!(!flags.global && !flags.unicode && !flags.unicode_sets);
+}
@@ -249,6 +249,21 @@ fn typed_entries_iterator() {
     assert_eq!(count, 2);
 }
 
+#[wasm_bindgen_test]
+fn iter_preserves_insertion_order() {
+    let set: Set<JsString> = Set::new_typed();
+    for value in ["c", "a", "b", "a"] {
+        set.add(&value.into());
+    }
+    assert!(set.delete(&"c".into()));
+    assert!(!set.delete(&"c".into()));
+    set.add(&"c".into());
+
+    let values: Vec<String> = set.iter().map(String::from).collect();
+    assert_eq!(values, ["a", "b", "c"]);
+    assert_eq!(set.try_iter().count(), 3);
+}
+
 #[wasm_bindgen_test]
 fn new_from_iterable() {
     let arr: Array<JsString> = Array::of(&[
@@ -649,3 +664,14 @@ fn empty_set_operations() {
     assert!(set.is_disjoint_from(&empty));
     assert!(empty.is_disjoint_from(&set));
 }
+
+#[wasm_bindgen_test]
+fn from_iter() {
+    let mut set: Set<JsString> = ["a", "b", "a"].into_iter().map(JsString::from).collect();
+    assert_eq!(set.size(), 2);
+    assert!(set.has(&"a".into()));
+    assert!(set.has(&"b".into()));
+
+    set.extend([JsString::from("c")]);
+    assert_eq!(set.size(), 3);
+}
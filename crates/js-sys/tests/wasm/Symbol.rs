@@ -108,6 +108,21 @@ fn key_for() {
     assert!(Symbol::key_for(&gensym(JsValue::undefined())).is_undefined());
 }
 
+#[wasm_bindgen_test]
+fn registered() {
+    let keys = ["proto.one", "proto.two", "proto.three"];
+    let symbols = Symbol::registered(&keys);
+    assert_eq!(symbols.len(), 3);
+    for (symbol, key) in symbols.iter().zip(keys) {
+        assert_eq!(Symbol::key_for(symbol), key);
+        assert_eq!(
+            JsValue::from(symbol.clone()),
+            JsValue::from(Symbol::for_(key))
+        );
+    }
+    assert!(Symbol::registered(&[]).is_empty());
+}
+
 #[allow(deprecated)]
 #[wasm_bindgen_test]
 fn to_string() {
@@ -158,6 +158,53 @@ async fn all_tuple_two() {
     assert_eq!(b, "hello");
 }
 
+#[wasm_bindgen_test]
+async fn join2() {
+    use js_sys::{JsString, Number};
+
+    let p1 = Promise::resolve(&Number::from(1));
+    let p2 = Promise::resolve(&JsString::from("hello"));
+    let (a, b) = Promise::join2(&p1, &p2).await.unwrap().into_tuple();
+    assert_eq!(a.value_of(), 1.0);
+    assert_eq!(b, "hello");
+}
+
+#[wasm_bindgen_test]
+async fn first_ok() {
+    use js_sys::{AggregateError, Array, Number};
+
+    let promises: Array<Promise<Number>> = Array::new_typed();
+    promises.push(&Promise::reject_typed(&JsValue::from("fail")));
+    promises.push(&Promise::resolve(&Number::from(42)));
+    let value: Number = Promise::first_ok(&promises).await.unwrap();
+    assert_eq!(value, 42);
+
+    let promises: Array<Promise<Number>> = Array::new_typed();
+    promises.push(&Promise::reject_typed(&JsValue::from("fail")));
+    let err = Promise::first_ok(&promises).await.unwrap_err();
+    assert!(err.is_instance_of::<AggregateError>());
+}
+
+#[wasm_bindgen_test]
+async fn retry() {
+    let mut calls = 0;
+    let promise = Promise::retry(
+        move || {
+            calls += 1;
+            if calls < 3 {
+                Promise::reject(&JsValue::from(calls))
+            } else {
+                Promise::resolve(&JsValue::from("ok"))
+            }
+        },
+        5,
+    );
+    assert_eq!(JsFuture::from(promise).await.unwrap(), "ok");
+
+    let promise = Promise::retry(|| Promise::reject(&JsValue::from("fail")), 2);
+    assert_eq!(JsFuture::from(promise).await.unwrap_err(), "fail");
+}
+
 #[wasm_bindgen_test]
 async fn all_tuple_three() {
     use js_sys::Number;
@@ -9,6 +9,7 @@ pub mod Array;
 pub mod ArrayBuffer;
 pub mod ArrayIterator;
 pub mod ArrayTuple;
+pub mod Atomics;
 pub mod BigInt;
 pub mod Boolean;
 pub mod DataView;